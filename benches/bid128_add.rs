@@ -22,29 +22,45 @@
  * SOFTWARE.
  */
 
-#![feature(test)]
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod benches_bid128_add {
+  use criterion::{criterion_group, Bencher, Criterion};
+  use dfp_number_sys::*;
 
-extern crate test;
+  fn bench_bid128_add_0001(b: &mut Bencher<'_>) {
+    let x = bid128_from_int32(2);
+    let y = bid128_from_int32(5);
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_add(x, y, 0, &mut flags));
+  }
 
-use dfp_number_sys::*;
-use test::Bencher;
+  fn bench_bid128_add_0002(b: &mut Bencher<'_>) {
+    let x = bid128_scalbn(bid128_from_int64(235678910), -8);
+    let y = bid128_scalbn(bid128_from_int64(235), -2);
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_add(x, y, 0, &mut flags));
+  }
 
-#[bench]
-fn bench_bid128_add_0001(b: &mut Bencher) {
-  let x = bid128_from_int32(2);
-  let y = bid128_from_int32(5);
-  let mut flags = FB_CLEAR;
-  b.iter(|| {
-    let _ = bid128_add(x, y, 0, &mut flags);
-  });
-}
+  fn bench_bid128_add_0003(b: &mut Bencher<'_>) {
+    let x = bid128_from_string("9999999999999999999999999999999999", RM_NEAREST_EVEN, &mut FB_CLEAR.clone());
+    let y = bid128_from_string("0.5555555555555555555555555555555555", RM_NEAREST_EVEN, &mut FB_CLEAR.clone());
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_add(x, y, 0, &mut flags));
+  }
+
+  fn benchmarks(c: &mut Criterion) {
+    c.bench_function("bid128_add_0001", bench_bid128_add_0001);
+    c.bench_function("bid128_add_0002", bench_bid128_add_0002);
+    c.bench_function("bid128_add_0003", bench_bid128_add_0003);
+  }
 
-#[bench]
-fn bench_bid128_add_0002(b: &mut Bencher) {
-  let x = bid128_scalbn(bid128_from_int64(235678910), -8);
-  let y = bid128_scalbn(bid128_from_int64(235), -2);
-  let mut flags = FB_CLEAR;
-  b.iter(|| {
-    let _ = bid128_add(x, y, 0, &mut flags);
-  });
+  criterion_group!(benches, benchmarks);
 }
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+criterion::criterion_main!(benches_bid128_add::benches);
+
+// benchmarked bindings are not available when arguments are passed by reference
+// or rounding modes and exception flags are global
+#[cfg(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags"))]
+fn main() {}
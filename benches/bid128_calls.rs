@@ -0,0 +1,96 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Benchmarks of small functions, dominated by the cost of calling the library.
+//! Inputs are hidden from the optimizer, so calls can not be hoisted out of the loop
+//! when the library is inlined into Rust code with cross-language LTO.
+//!
+//! Settings of the library are compared by running the benchmarks with different values
+//! of `DFP_OPT_LEVEL` and `DFP_TARGET_CPU` environment variables, and with and without
+//! the `cross-language-lto` feature (requires clang).
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod benches_bid128_calls {
+  use criterion::{criterion_group, Bencher, Criterion};
+  use dfp_number_sys::*;
+  use std::hint::black_box;
+
+  fn d128(s: &str) -> BID128 {
+    bid128_from_string(s, RM_NEAREST_EVEN, &mut FB_CLEAR.clone())
+  }
+
+  fn bench_bid128_abs_0001(b: &mut Bencher<'_>) {
+    let x = d128("-1.25");
+    b.iter(|| bid128_abs(black_box(x)));
+  }
+
+  fn bench_bid128_is_zero_0001(b: &mut Bencher<'_>) {
+    let x = d128("0E-10");
+    b.iter(|| bid128_is_zero(black_box(x)));
+  }
+
+  fn bench_bid128_is_finite_0001(b: &mut Bencher<'_>) {
+    let x = d128("1.25");
+    b.iter(|| bid128_is_finite(black_box(x)));
+  }
+
+  fn bench_bid128_quantexp_0001(b: &mut Bencher<'_>) {
+    let x = d128("1.25");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_quantexp(black_box(x), &mut flags));
+  }
+
+  fn bench_bid128_quiet_less_0001(b: &mut Bencher<'_>) {
+    let x = d128("1.25");
+    let y = d128("1.5");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_quiet_less(black_box(x), black_box(y), &mut flags));
+  }
+
+  fn bench_bid128_add_0001(b: &mut Bencher<'_>) {
+    let x = d128("9999999999999999999999999999999999");
+    let y = d128("0.5555555555555555555555555555555555");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_add(black_box(x), black_box(y), RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn benchmarks(c: &mut Criterion) {
+    c.bench_function("bid128_abs_0001", bench_bid128_abs_0001);
+    c.bench_function("bid128_is_zero_0001", bench_bid128_is_zero_0001);
+    c.bench_function("bid128_is_finite_0001", bench_bid128_is_finite_0001);
+    c.bench_function("bid128_quantexp_0001", bench_bid128_quantexp_0001);
+    c.bench_function("bid128_quiet_less_0001", bench_bid128_quiet_less_0001);
+    c.bench_function("bid128_add_0001", bench_bid128_add_0001);
+  }
+
+  criterion_group!(benches, benchmarks);
+}
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+criterion::criterion_main!(benches_bid128_calls::benches);
+
+// benchmarked bindings are not available when arguments are passed by reference
+// or rounding modes and exception flags are global
+#[cfg(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags"))]
+fn main() {}
@@ -22,36 +22,61 @@
  * SOFTWARE.
  */
 
-#![feature(test)]
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod benches_bid128_from_string {
+  use criterion::{criterion_group, Bencher, Criterion};
+  use dfp_number_sys::*;
 
-extern crate test;
+  fn bench_bid128_from_string_0001(b: &mut Bencher<'_>) {
+    let mut flags = FB_CLEAR;
+    let round = RM_NEAREST_EVEN;
+    b.iter(|| bid128_from_string("128374.9458", round, &mut flags));
+  }
 
-use dfp_number_sys::*;
-use test::Bencher;
+  fn bench_bid128_from_string_0002(b: &mut Bencher<'_>) {
+    let mut flags = FB_CLEAR;
+    let round = RM_NEAREST_EVEN;
+    b.iter(|| bid128_from_string("-0.45985E-6", round, &mut flags));
+  }
 
-#[bench]
-fn bench_bid128_from_string_0001(b: &mut Bencher) {
-  let mut flags = FB_CLEAR;
-  let round = RM_NEAREST_EVEN;
-  b.iter(|| {
-    let _ = bid128_from_string("128374.9458", round, &mut flags);
-  });
-}
+  fn bench_bid128_from_string_0003(b: &mut Bencher<'_>) {
+    let mut flags = FB_CLEAR;
+    let round = RM_NEAREST_EVEN;
+    b.iter(|| bid128_from_string("9999999999999999999999999999999999", round, &mut flags));
+  }
 
-#[bench]
-fn bench_bid128_from_string_0002(b: &mut Bencher) {
-  let mut flags = FB_CLEAR;
-  let round = RM_NEAREST_EVEN;
-  b.iter(|| {
-    let _ = bid128_from_string("-0.45985E-6", round, &mut flags);
-  });
-}
+  fn bench_bid128_from_bytes_0001(b: &mut Bencher<'_>) {
+    let mut flags = FB_CLEAR;
+    let round = RM_NEAREST_EVEN;
+    b.iter(|| bid128_from_bytes(b"128374.9458", round, &mut flags));
+  }
+
+  fn bench_bid128_from_bytes_0002(b: &mut Bencher<'_>) {
+    let mut flags = FB_CLEAR;
+    let round = RM_NEAREST_EVEN;
+    let line = b"128374.9458;-0.45985E-6;9999999999999999999999999999999999";
+    b.iter(|| {
+      for field in line.split(|b| *b == b';') {
+        let _ = bid128_from_bytes(field, round, &mut flags);
+      }
+    });
+  }
 
-#[bench]
-fn bench_bid128_from_string_0003(b: &mut Bencher) {
-  let mut flags = FB_CLEAR;
-  let round = RM_NEAREST_EVEN;
-  b.iter(|| {
-    let _ = bid128_from_string("9999999999999999999999999999999999", round, &mut flags);
-  });
+  fn benchmarks(c: &mut Criterion) {
+    c.bench_function("bid128_from_string_0001", bench_bid128_from_string_0001);
+    c.bench_function("bid128_from_string_0002", bench_bid128_from_string_0002);
+    c.bench_function("bid128_from_string_0003", bench_bid128_from_string_0003);
+    c.bench_function("bid128_from_bytes_0001", bench_bid128_from_bytes_0001);
+    c.bench_function("bid128_from_bytes_0002", bench_bid128_from_bytes_0002);
+  }
+
+  criterion_group!(benches, benchmarks);
 }
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+criterion::criterion_main!(benches_bid128_from_string::benches);
+
+// benchmarked bindings are not available when arguments are passed by reference
+// or rounding modes and exception flags are global
+#[cfg(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags"))]
+fn main() {}
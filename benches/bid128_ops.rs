@@ -0,0 +1,135 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod benches_bid128_ops {
+  use criterion::{criterion_group, Bencher, Criterion};
+  use dfp_number_sys::*;
+
+  fn d128(s: &str) -> BID128 {
+    let mut flags = FB_CLEAR;
+    bid128_from_string(s, RM_NEAREST_EVEN, &mut flags)
+  }
+
+  fn bench_bid128_div_0001(b: &mut Bencher<'_>) {
+    let x = d128("235.678910");
+    let y = d128("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_div(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid128_div_0002(b: &mut Bencher<'_>) {
+    let x = d128("1");
+    let y = d128("3");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_div(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid128_sqrt_0001(b: &mut Bencher<'_>) {
+    let x = d128("144");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_sqrt(x, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid128_sqrt_0002(b: &mut Bencher<'_>) {
+    let x = d128("2");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_sqrt(x, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid128_pow_0001(b: &mut Bencher<'_>) {
+    let x = d128("1.05");
+    let y = d128("12");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_pow(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid128_quantize_0001(b: &mut Bencher<'_>) {
+    let x = d128("235.678910");
+    let y = d128("0.01");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_quantize(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid128_quiet_equal_0001(b: &mut Bencher<'_>) {
+    let x = d128("235.678910");
+    let y = d128("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_quiet_equal(x, y, &mut flags));
+  }
+
+  fn bench_bid128_quiet_less_0001(b: &mut Bencher<'_>) {
+    let x = d128("235.678910");
+    let y = d128("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_quiet_less(x, y, &mut flags));
+  }
+
+  fn bench_bid128_from_int64_0001(b: &mut Bencher<'_>) {
+    b.iter(|| bid128_from_int64(1_234_567_890));
+  }
+
+  fn bench_bid128_to_int64_int_0001(b: &mut Bencher<'_>) {
+    let x = d128("1234567.89");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_to_int64_int(x, &mut flags));
+  }
+
+  fn bench_bid128_to_bid64_0001(b: &mut Bencher<'_>) {
+    let x = d128("-0.45985E-6");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_to_bid64(x, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid128_to_bid32_0001(b: &mut Bencher<'_>) {
+    let x = d128("-0.45985E-6");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_to_bid32(x, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn benchmarks(c: &mut Criterion) {
+    c.bench_function("bid128_div_0001", bench_bid128_div_0001);
+    c.bench_function("bid128_div_0002", bench_bid128_div_0002);
+    c.bench_function("bid128_sqrt_0001", bench_bid128_sqrt_0001);
+    c.bench_function("bid128_sqrt_0002", bench_bid128_sqrt_0002);
+    c.bench_function("bid128_pow_0001", bench_bid128_pow_0001);
+    c.bench_function("bid128_quantize_0001", bench_bid128_quantize_0001);
+    c.bench_function("bid128_quiet_equal_0001", bench_bid128_quiet_equal_0001);
+    c.bench_function("bid128_quiet_less_0001", bench_bid128_quiet_less_0001);
+    c.bench_function("bid128_from_int64_0001", bench_bid128_from_int64_0001);
+    c.bench_function("bid128_to_int64_int_0001", bench_bid128_to_int64_int_0001);
+    c.bench_function("bid128_to_bid64_0001", bench_bid128_to_bid64_0001);
+    c.bench_function("bid128_to_bid32_0001", bench_bid128_to_bid32_0001);
+  }
+
+  criterion_group!(benches, benchmarks);
+}
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+criterion::criterion_main!(benches_bid128_ops::benches);
+
+// benchmarked bindings are not available when arguments are passed by reference
+// or rounding modes and exception flags are global
+#[cfg(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags"))]
+fn main() {}
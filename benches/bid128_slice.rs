@@ -0,0 +1,95 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod benches_bid128_slice {
+  use criterion::{criterion_group, Bencher, Criterion};
+  use dfp_number_sys::*;
+
+  fn decimals() -> Vec<BID128> {
+    (0..4096).map(|i| bid128_scalbn(bid128_from_int32(i * 37 + 5), -2)).collect()
+  }
+
+  fn bench_bid128_add_loop_0001(b: &mut Bencher<'_>) {
+    let x = decimals();
+    let mut out = x.clone();
+    b.iter(|| {
+      let mut flags = FB_CLEAR;
+      for ((x, y), out) in x.iter().zip(&x).zip(out.iter_mut()) {
+        *out = bid128_add(*x, *y, RM_NEAREST_EVEN, &mut flags);
+      }
+      flags
+    });
+  }
+
+  fn bench_bid128_add_slices_0001(b: &mut Bencher<'_>) {
+    let x = decimals();
+    let mut out = x.clone();
+    b.iter(|| bid128_add_slices(&x, &x, &mut out, RM_NEAREST_EVEN));
+  }
+
+  fn bench_bid128_mul_slices_0001(b: &mut Bencher<'_>) {
+    let x = decimals();
+    let mut out = x.clone();
+    b.iter(|| bid128_mul_slices(&x, &x, &mut out, RM_NEAREST_EVEN));
+  }
+
+  fn bench_bid128_compare_into_mask_0001(b: &mut Bencher<'_>) {
+    let x = decimals();
+    let y: Vec<BID128> = x.iter().rev().copied().collect();
+    let mut mask = vec![false; x.len()];
+    b.iter(|| bid128_compare_into_mask(&x, &y, Comparison::Less, &mut mask));
+  }
+
+  fn bench_bid128_sum_0001(b: &mut Bencher<'_>) {
+    let x = decimals();
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_sum(&x, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid128_dot_0001(b: &mut Bencher<'_>) {
+    let x = decimals();
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_dot(&x, &x, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn benchmarks(c: &mut Criterion) {
+    c.bench_function("bid128_add_loop_0001", bench_bid128_add_loop_0001);
+    c.bench_function("bid128_add_slices_0001", bench_bid128_add_slices_0001);
+    c.bench_function("bid128_mul_slices_0001", bench_bid128_mul_slices_0001);
+    c.bench_function("bid128_compare_into_mask_0001", bench_bid128_compare_into_mask_0001);
+    c.bench_function("bid128_sum_0001", bench_bid128_sum_0001);
+    c.bench_function("bid128_dot_0001", bench_bid128_dot_0001);
+  }
+
+  criterion_group!(benches, benchmarks);
+}
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+criterion::criterion_main!(benches_bid128_slice::benches);
+
+// benchmarked bindings are not available when arguments are passed by reference
+// or rounding modes and exception flags are global
+#[cfg(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags"))]
+fn main() {}
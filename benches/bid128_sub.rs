@@ -0,0 +1,66 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod benches_bid128_sub {
+  use criterion::{criterion_group, Bencher, Criterion};
+  use dfp_number_sys::*;
+
+  fn bench_bid128_sub_0001(b: &mut Bencher<'_>) {
+    let x = bid128_from_int32(2);
+    let y = bid128_from_int32(5);
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_sub(x, y, 0, &mut flags));
+  }
+
+  fn bench_bid128_sub_0002(b: &mut Bencher<'_>) {
+    let x = bid128_scalbn(bid128_from_int64(235678910), -8);
+    let y = bid128_scalbn(bid128_from_int64(235), -2);
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_sub(x, y, 0, &mut flags));
+  }
+
+  fn bench_bid128_sub_0003(b: &mut Bencher<'_>) {
+    let x = bid128_from_string("9999999999999999999999999999999999", RM_NEAREST_EVEN, &mut FB_CLEAR.clone());
+    let y = bid128_from_string("0.5555555555555555555555555555555555", RM_NEAREST_EVEN, &mut FB_CLEAR.clone());
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_sub(x, y, 0, &mut flags));
+  }
+
+  fn benchmarks(c: &mut Criterion) {
+    c.bench_function("bid128_sub_0001", bench_bid128_sub_0001);
+    c.bench_function("bid128_sub_0002", bench_bid128_sub_0002);
+    c.bench_function("bid128_sub_0003", bench_bid128_sub_0003);
+  }
+
+  criterion_group!(benches, benchmarks);
+}
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+criterion::criterion_main!(benches_bid128_sub::benches);
+
+// benchmarked bindings are not available when arguments are passed by reference
+// or rounding modes and exception flags are global
+#[cfg(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags"))]
+fn main() {}
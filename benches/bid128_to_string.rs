@@ -22,27 +22,64 @@
  * SOFTWARE.
  */
 
-#![feature(test)]
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod benches_bid128_to_string {
+  use criterion::{criterion_group, Bencher, Criterion};
+  use dfp_number_sys::*;
 
-extern crate test;
+  fn bench_bid128_to_string_0001(b: &mut Bencher<'_>) {
+    let x = bid128_from_int32(2);
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_to_string(x, &mut flags));
+  }
 
-use dfp_number_sys::*;
-use test::Bencher;
+  fn bench_bid128_to_string_0002(b: &mut Bencher<'_>) {
+    let x = bid128_scalbn(bid128_from_int64(235678910), -8);
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid128_to_string(x, &mut flags));
+  }
 
-#[bench]
-fn bench_bid128_to_string_0001(b: &mut Bencher) {
-  let x = bid128_from_int32(2);
-  let mut flags = FB_CLEAR;
-  b.iter(|| {
-    let _ = bid128_to_string(x, &mut flags);
-  });
-}
+  fn bench_bid128_to_str_buf_0001(b: &mut Bencher<'_>) {
+    let x = bid128_from_int32(2);
+    let mut flags = FB_CLEAR;
+    let mut buf = [0_u8; BID128_STRING_LENGTH];
+    b.iter(|| bid128_to_str_buf(x, &mut buf, &mut flags).len());
+  }
+
+  fn bench_bid128_to_str_buf_0002(b: &mut Bencher<'_>) {
+    let x = bid128_scalbn(bid128_from_int64(235678910), -8);
+    let mut flags = FB_CLEAR;
+    let mut buf = [0_u8; BID128_STRING_LENGTH];
+    b.iter(|| bid128_to_str_buf(x, &mut buf, &mut flags).len());
+  }
+
+  fn bench_bid128_write_to_0001(b: &mut Bencher<'_>) {
+    let x = bid128_scalbn(bid128_from_int64(235678910), -8);
+    let mut flags = FB_CLEAR;
+    let mut s = String::with_capacity(1024 * BID128_STRING_LENGTH);
+    b.iter(|| {
+      if s.len() > 1000 * BID128_STRING_LENGTH {
+        s.clear();
+      }
+      let _ = bid128_write_to(x, &mut s, &mut flags);
+    });
+  }
 
-#[bench]
-fn bench_bid128_to_string_0002(b: &mut Bencher) {
-  let x = bid128_scalbn(bid128_from_int64(235678910), -8);
-  let mut flags = FB_CLEAR;
-  b.iter(|| {
-    let _ = bid128_to_string(x, &mut flags);
-  });
+  fn benchmarks(c: &mut Criterion) {
+    c.bench_function("bid128_to_string_0001", bench_bid128_to_string_0001);
+    c.bench_function("bid128_to_string_0002", bench_bid128_to_string_0002);
+    c.bench_function("bid128_to_str_buf_0001", bench_bid128_to_str_buf_0001);
+    c.bench_function("bid128_to_str_buf_0002", bench_bid128_to_str_buf_0002);
+    c.bench_function("bid128_write_to_0001", bench_bid128_write_to_0001);
+  }
+
+  criterion_group!(benches, benchmarks);
 }
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+criterion::criterion_main!(benches_bid128_to_string::benches);
+
+// benchmarked bindings are not available when arguments are passed by reference
+// or rounding modes and exception flags are global
+#[cfg(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags"))]
+fn main() {}
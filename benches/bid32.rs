@@ -0,0 +1,173 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod benches_bid32 {
+  use criterion::{criterion_group, Bencher, Criterion};
+  use dfp_number_sys::*;
+
+  fn d32(s: &str) -> BID32 {
+    let mut flags = FB_CLEAR;
+    bid32_from_string(s, RM_NEAREST_EVEN, &mut flags)
+  }
+
+  fn bench_bid32_add_0001(b: &mut Bencher<'_>) {
+    let x = d32("235.678910");
+    let y = d32("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_add(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_sub_0001(b: &mut Bencher<'_>) {
+    let x = d32("235.678910");
+    let y = d32("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_sub(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_mul_0001(b: &mut Bencher<'_>) {
+    let x = d32("235.678910");
+    let y = d32("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_mul(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_div_0001(b: &mut Bencher<'_>) {
+    let x = d32("235.678910");
+    let y = d32("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_div(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_div_0002(b: &mut Bencher<'_>) {
+    let x = d32("1");
+    let y = d32("3");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_div(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_sqrt_0001(b: &mut Bencher<'_>) {
+    let x = d32("144");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_sqrt(x, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_sqrt_0002(b: &mut Bencher<'_>) {
+    let x = d32("2");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_sqrt(x, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_pow_0001(b: &mut Bencher<'_>) {
+    let x = d32("1.05");
+    let y = d32("12");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_pow(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_quantize_0001(b: &mut Bencher<'_>) {
+    let x = d32("235.678910");
+    let y = d32("0.01");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_quantize(x, y, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_quiet_equal_0001(b: &mut Bencher<'_>) {
+    let x = d32("235.678910");
+    let y = d32("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_quiet_equal(x, y, &mut flags));
+  }
+
+  fn bench_bid32_quiet_less_0001(b: &mut Bencher<'_>) {
+    let x = d32("235.678910");
+    let y = d32("2.35");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_quiet_less(x, y, &mut flags));
+  }
+
+  fn bench_bid32_from_int64_0001(b: &mut Bencher<'_>) {
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_from_int64(1_234_567_890, RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_to_int64_int_0001(b: &mut Bencher<'_>) {
+    let x = d32("1234567.89");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_to_int64_int(x, &mut flags));
+  }
+
+  fn bench_bid32_from_string_0001(b: &mut Bencher<'_>) {
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_from_string("-0.45985E-6", RM_NEAREST_EVEN, &mut flags));
+  }
+
+  fn bench_bid32_to_string_0001(b: &mut Bencher<'_>) {
+    let x = d32("-0.45985E-6");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_to_string(x, &mut flags));
+  }
+
+  fn bench_bid32_to_bid128_0001(b: &mut Bencher<'_>) {
+    let x = d32("-0.45985E-6");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_to_bid128(x, &mut flags));
+  }
+
+  fn bench_bid32_to_bid64_0001(b: &mut Bencher<'_>) {
+    let x = d32("-0.45985E-6");
+    let mut flags = FB_CLEAR;
+    b.iter(|| bid32_to_bid64(x, &mut flags));
+  }
+
+  fn benchmarks(c: &mut Criterion) {
+    c.bench_function("bid32_add_0001", bench_bid32_add_0001);
+    c.bench_function("bid32_sub_0001", bench_bid32_sub_0001);
+    c.bench_function("bid32_mul_0001", bench_bid32_mul_0001);
+    c.bench_function("bid32_div_0001", bench_bid32_div_0001);
+    c.bench_function("bid32_div_0002", bench_bid32_div_0002);
+    c.bench_function("bid32_sqrt_0001", bench_bid32_sqrt_0001);
+    c.bench_function("bid32_sqrt_0002", bench_bid32_sqrt_0002);
+    c.bench_function("bid32_pow_0001", bench_bid32_pow_0001);
+    c.bench_function("bid32_quantize_0001", bench_bid32_quantize_0001);
+    c.bench_function("bid32_quiet_equal_0001", bench_bid32_quiet_equal_0001);
+    c.bench_function("bid32_quiet_less_0001", bench_bid32_quiet_less_0001);
+    c.bench_function("bid32_from_int64_0001", bench_bid32_from_int64_0001);
+    c.bench_function("bid32_to_int64_int_0001", bench_bid32_to_int64_int_0001);
+    c.bench_function("bid32_from_string_0001", bench_bid32_from_string_0001);
+    c.bench_function("bid32_to_string_0001", bench_bid32_to_string_0001);
+    c.bench_function("bid32_to_bid128_0001", bench_bid32_to_bid128_0001);
+    c.bench_function("bid32_to_bid64_0001", bench_bid32_to_bid64_0001);
+  }
+
+  criterion_group!(benches, benchmarks);
+}
+
+#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+criterion::criterion_main!(benches_bid32::benches);
+
+// benchmarked bindings are not available when arguments are passed by reference
+// or rounding modes and exception flags are global
+#[cfg(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags"))]
+fn main() {}
@@ -22,7 +22,8 @@
  * SOFTWARE.
  */
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
 #[cfg(not(feature = "call-by-reference"))]
 const DECIMAL_CALL_BY_REFERENCE: &str = "0";
@@ -39,21 +40,603 @@ const DECIMAL_GLOBAL_EXCEPTION_FLAGS: &str = "0";
 #[cfg(feature = "global-exception-flags")]
 const DECIMAL_GLOBAL_EXCEPTION_FLAGS: &str = "1";
 
-#[cfg(target_arch = "x86_64")]
-const ARCHITECTURE: &str = "ix86";
+/// Returns the value of the configuration variable set by Cargo for the compilation target.
+fn target_cfg(name: &str) -> String {
+  std::env::var(format!("CARGO_CFG_TARGET_{name}")).unwrap_or_default()
+}
+
+/// Returns the architecture recognized by the library for the target architecture and endianness.
+fn architecture(arch: &str, endian: &str) -> &'static str {
+  match (arch, endian) {
+    ("x86_64" | "x86" | "arm" | "wasm32", "little") => "ix86",
+    ("aarch64" | "riscv64" | "powerpc64", "little") => "efi2",
+    ("powerpc64" | "s390x", "big") => "sparc",
+    _ => panic!("unsupported target architecture: {arch} ({endian} endian)"),
+  }
+}
+
+/// Returns the operating system recognized by the library for the target operating system.
+fn operating_system(os: &str) -> &'static str {
+  match os {
+    // WebAssembly targets are compiled against wasi-libc or emscripten headers
+    "linux" | "wasi" | "emscripten" | "unknown" => "linux",
+    "windows" => "win64",
+    "macos" => "darwin",
+    _ => panic!("unsupported target operating system: {os}"),
+  }
+}
+
+/// Sources always compiled: shared tables, rounding, exception flags and the conversions
+/// between formats, used internally by the arithmetic of every format.
+#[rustfmt::skip]
+const COMMON_SOURCES: &[&str] = &[
+  "src/bid128_2_str_tables.c",
+  "src/bid128.c",
+  "src/bid128_compare.c",
+  "src/bid64_nearbyintd.c",
+  "src/bid64_round_integral.c",
+  "src/bid64_to_bid128.c",
+  "src/bid32_to_bid128.c",
+  "src/bid32_to_bid64.c",
+  "src/bid_convert_data.c",
+  "src/bid_decimal_data.c",
+  "src/bid_decimal_globals.c",
+  "src/bid_feclearexcept.c",
+  "src/bid_fegetexceptflag.c",
+  "src/bid_feraiseexcept.c",
+  "src/bid_fesetexceptflag.c",
+  "src/bid_fetestexcept.c",
+  "src/bid_flag_operations.c",
+  "src/bid_from_int.c",
+  "src/bid_round.c",
+];
+
+/// Sources of operations on 32-bit decimals, compiled with feature `bid32`.
+#[rustfmt::skip]
+const BID32_SOURCES: &[&str] = &[
+  "src/bid32_add.c",
+  "src/bid32_compare.c",
+  "src/bid32_div.c",
+  "src/bid32_fdimd.c",
+  "src/bid32_fma.c",
+  "src/bid32_fmod.c",
+  "src/bid32_frexp.c",
+  "src/bid32_ldexp.c",
+  "src/bid32_llquantexpd.c",
+  "src/bid32_llrintd.c",
+  "src/bid32_llround.c",
+  "src/bid32_logb.c",
+  "src/bid32_logbd.c",
+  "src/bid32_lrintd.c",
+  "src/bid32_lround.c",
+  "src/bid32_minmax.c",
+  "src/bid32_modf.c",
+  "src/bid32_mul.c",
+  "src/bid32_nearbyintd.c",
+  "src/bid32_next.c",
+  "src/bid32_nexttowardd.c",
+  "src/bid32_noncomp.c",
+  "src/bid32_quantexpd.c",
+  "src/bid32_quantize.c",
+  "src/bid32_quantumd.c",
+  "src/bid32_rem.c",
+  "src/bid32_round_integral.c",
+  "src/bid32_scalb.c",
+  "src/bid32_scalbl.c",
+  "src/bid32_sqrt.c",
+  "src/bid32_string.c",
+  "src/bid32_sub.c",
+  "src/bid32_to_int16.c",
+  "src/bid32_to_int32.c",
+  "src/bid32_to_int64.c",
+  "src/bid32_to_int8.c",
+  "src/bid32_to_uint16.c",
+  "src/bid32_to_uint32.c",
+  "src/bid32_to_uint64.c",
+  "src/bid32_to_uint8.c",
+  "src/strtod32.c",
+  "src/wcstod32.c",
+];
+
+/// Sources of operations on 64-bit decimals, compiled with feature `bid64`.
+#[rustfmt::skip]
+const BID64_SOURCES: &[&str] = &[
+  "src/bid64_add.c",
+  "src/bid64_compare.c",
+  "src/bid64_div.c",
+  "src/bid64_fdimd.c",
+  "src/bid64_fma.c",
+  "src/bid64_fmod.c",
+  "src/bid64_frexp.c",
+  "src/bid64_ldexp.c",
+  "src/bid64_llquantexpd.c",
+  "src/bid64_llrintd.c",
+  "src/bid64_llround.c",
+  "src/bid64_logb.c",
+  "src/bid64_logbd.c",
+  "src/bid64_lrintd.c",
+  "src/bid64_lround.c",
+  "src/bid64_minmax.c",
+  "src/bid64_modf.c",
+  "src/bid64_mul.c",
+  "src/bid64_next.c",
+  "src/bid64_nexttowardd.c",
+  "src/bid64_noncomp.c",
+  "src/bid64_quantexpd.c",
+  "src/bid64_quantize.c",
+  "src/bid64_quantumd.c",
+  "src/bid64_rem.c",
+  "src/bid64_scalb.c",
+  "src/bid64_scalbl.c",
+  "src/bid64_sqrt.c",
+  "src/bid64_string.c",
+  "src/bid64_to_int16.c",
+  "src/bid64_to_int32.c",
+  "src/bid64_to_int64.c",
+  "src/bid64_to_int8.c",
+  "src/bid64_to_uint16.c",
+  "src/bid64_to_uint32.c",
+  "src/bid64_to_uint64.c",
+  "src/bid64_to_uint8.c",
+  "src/strtod64.c",
+  "src/wcstod64.c",
+];
+
+/// Sources of operations on 128-bit decimals, compiled with feature `bid128`.
+#[rustfmt::skip]
+const BID128_SOURCES: &[&str] = &[
+  "src/bid128_add.c",
+  "src/bid128_div.c",
+  "src/bid128_fdimd.c",
+  "src/bid128_fma.c",
+  "src/bid128_fmod.c",
+  "src/bid128_frexp.c",
+  "src/bid128_ldexp.c",
+  "src/bid128_llquantexpd.c",
+  "src/bid128_llrintd.c",
+  "src/bid128_llround.c",
+  "src/bid128_logb.c",
+  "src/bid128_logbd.c",
+  "src/bid128_lrintd.c",
+  "src/bid128_lround.c",
+  "src/bid128_minmax.c",
+  "src/bid128_modf.c",
+  "src/bid128_mul.c",
+  "src/bid128_nearbyintd.c",
+  "src/bid128_next.c",
+  "src/bid128_nexttowardd.c",
+  "src/bid128_noncomp.c",
+  "src/bid128_quantexpd.c",
+  "src/bid128_quantize.c",
+  "src/bid128_quantumd.c",
+  "src/bid128_rem.c",
+  "src/bid128_round_integral.c",
+  "src/bid128_scalb.c",
+  "src/bid128_scalbl.c",
+  "src/bid128_sqrt.c",
+  "src/bid128_string.c",
+  "src/bid128_to_int16.c",
+  "src/bid128_to_int32.c",
+  "src/bid128_to_int64.c",
+  "src/bid128_to_int8.c",
+  "src/bid128_to_uint16.c",
+  "src/bid128_to_uint32.c",
+  "src/bid128_to_uint64.c",
+  "src/bid128_to_uint8.c",
+  "src/strtod128.c",
+  "src/wcstod128.c",
+];
+
+/// Sources of the 128-bit binary floating-point kernels used by transcendental functions.
+#[rustfmt::skip]
+const TRANSCENDENTAL_SOURCES: &[&str] = &[
+  "float128/dpml_exception.c",
+  "float128/dpml_four_over_pi.c",
+  "float128/dpml_ux_bessel.c",
+  "float128/dpml_ux_bid.c",
+  "float128/dpml_ux_cbrt.c",
+  "float128/dpml_ux_erf.c",
+  "float128/dpml_ux_exp.c",
+  "float128/dpml_ux_int.c",
+  "float128/dpml_ux_inv_hyper.c",
+  "float128/dpml_ux_inv_trig.c",
+  "float128/dpml_ux_lgamma.c",
+  "float128/dpml_ux_log.c",
+  "float128/dpml_ux_mod.c",
+  "float128/dpml_ux_ops.c",
+  "float128/dpml_ux_ops_64.c",
+  "float128/dpml_ux_pow.c",
+  "float128/dpml_ux_powi.c",
+  "float128/dpml_ux_sqrt.c",
+  "float128/dpml_ux_trig.c",
+  "float128/sqrt_tab_t.c",
+];
+
+/// Sources of transcendental functions on 32-bit decimals, compiled with features `bid32` and `transcendental`.
+#[rustfmt::skip]
+const BID32_TRANSCENDENTAL_SOURCES: &[&str] = &[
+  "src/bid32_acos.c",
+  "src/bid32_acosh.c",
+  "src/bid32_asin.c",
+  "src/bid32_asinh.c",
+  "src/bid32_atan2.c",
+  "src/bid32_atan.c",
+  "src/bid32_atanh.c",
+  "src/bid32_cbrt.c",
+  "src/bid32_cos.c",
+  "src/bid32_cosh.c",
+  "src/bid32_erf.c",
+  "src/bid32_erfc.c",
+  "src/bid32_exp10.c",
+  "src/bid32_exp2.c",
+  "src/bid32_exp.c",
+  "src/bid32_expm1.c",
+  "src/bid32_hypot.c",
+  "src/bid32_lgamma.c",
+  "src/bid32_log10.c",
+  "src/bid32_log1p.c",
+  "src/bid32_log2.c",
+  "src/bid32_log.c",
+  "src/bid32_pow.c",
+  "src/bid32_sin.c",
+  "src/bid32_sinh.c",
+  "src/bid32_tan.c",
+  "src/bid32_tanh.c",
+  "src/bid32_tgamma.c",
+];
+
+/// Sources of transcendental functions on 64-bit decimals, compiled with features `bid64` and `transcendental`.
+#[rustfmt::skip]
+const BID64_TRANSCENDENTAL_SOURCES: &[&str] = &[
+  "src/bid64_acos.c",
+  "src/bid64_acosh.c",
+  "src/bid64_asin.c",
+  "src/bid64_asinh.c",
+  "src/bid64_atan2.c",
+  "src/bid64_atan.c",
+  "src/bid64_atanh.c",
+  "src/bid64_cbrt.c",
+  "src/bid64_cos.c",
+  "src/bid64_cosh.c",
+  "src/bid64_erf.c",
+  "src/bid64_erfc.c",
+  "src/bid64_exp10.c",
+  "src/bid64_exp2.c",
+  "src/bid64_exp.c",
+  "src/bid64_expm1.c",
+  "src/bid64_hypot.c",
+  "src/bid64_lgamma.c",
+  "src/bid64_log10.c",
+  "src/bid64_log1p.c",
+  "src/bid64_log2.c",
+  "src/bid64_log.c",
+  "src/bid64_pow.c",
+  "src/bid64_sin.c",
+  "src/bid64_sinh.c",
+  "src/bid64_tan.c",
+  "src/bid64_tanh.c",
+  "src/bid64_tgamma.c",
+];
+
+/// Sources of transcendental functions on 128-bit decimals, compiled with features `bid128` and `transcendental`.
+#[rustfmt::skip]
+const BID128_TRANSCENDENTAL_SOURCES: &[&str] = &[
+  "src/bid128_acos.c",
+  "src/bid128_acosh.c",
+  "src/bid128_asin.c",
+  "src/bid128_asinh.c",
+  "src/bid128_atan2.c",
+  "src/bid128_atan.c",
+  "src/bid128_atanh.c",
+  "src/bid128_cbrt.c",
+  "src/bid128_cos.c",
+  "src/bid128_cosh.c",
+  "src/bid128_erf.c",
+  "src/bid128_erfc.c",
+  "src/bid128_exp10.c",
+  "src/bid128_exp2.c",
+  "src/bid128_exp.c",
+  "src/bid128_expm1.c",
+  "src/bid128_hypot.c",
+  "src/bid128_lgamma.c",
+  "src/bid128_log10.c",
+  "src/bid128_log1p.c",
+  "src/bid128_log2.c",
+  "src/bid128_log.c",
+  "src/bid128_pow.c",
+  "src/bid128_sin.c",
+  "src/bid128_sinh.c",
+  "src/bid128_tan.c",
+  "src/bid128_tanh.c",
+  "src/bid128_tgamma.c",
+];
+
+/// Sources of conversions between binary and decimal floating-point formats, compiled with feature `binary-conversions`.
+#[rustfmt::skip]
+const BINARY_CONVERSIONS_SOURCES: &[&str] = &[
+  "src/bid_binarydecimal.c",
+];
+
+/// Sources of conversions between BID and DPD encodings, compiled with feature `dpd`.
+#[rustfmt::skip]
+const DPD_SOURCES: &[&str] = &[
+  "src/bid_dpd.c",
+];
+
+/// Returns the source files of the library selected by enabled features.
+fn sources() -> Vec<&'static str> {
+  let transcendental = cfg!(feature = "transcendental");
+  let mut sources = COMMON_SOURCES.to_vec();
+  for (enabled, format_sources, transcendental_sources) in [
+    (cfg!(feature = "bid32"), BID32_SOURCES, BID32_TRANSCENDENTAL_SOURCES),
+    (cfg!(feature = "bid64"), BID64_SOURCES, BID64_TRANSCENDENTAL_SOURCES),
+    (cfg!(feature = "bid128"), BID128_SOURCES, BID128_TRANSCENDENTAL_SOURCES),
+  ] {
+    if enabled {
+      sources.extend(format_sources);
+      if transcendental {
+        sources.extend(transcendental_sources);
+      }
+    }
+  }
+  if transcendental {
+    sources.extend(TRANSCENDENTAL_SOURCES);
+  }
+  if cfg!(feature = "binary-conversions") {
+    sources.extend(BINARY_CONVERSIONS_SOURCES);
+  }
+  if cfg!(feature = "dpd") {
+    sources.extend(DPD_SOURCES);
+  }
+  sources
+}
+
+/// Returns the symbols of the library required by the bindings of enabled features.
+fn required_symbols() -> Vec<&'static str> {
+  let transcendental = cfg!(feature = "transcendental");
+  let mut symbols = vec![];
+  if cfg!(feature = "bid32") {
+    symbols.extend(["__bid32_add", "__bid32_from_string", "__bid32_to_string", "__bid32_to_bid128"]);
+    if transcendental {
+      symbols.push("__bid32_pow");
+    }
+  }
+  if cfg!(feature = "bid64") {
+    symbols.extend(["__bid64_add", "__bid64_from_string", "__bid64_to_string", "__bid64_to_bid128"]);
+    if transcendental {
+      symbols.push("__bid64_pow");
+    }
+  }
+  if cfg!(feature = "bid128") {
+    symbols.extend(["__bid128_add", "__bid128_from_string", "__bid128_to_string", "__bid_strtod128"]);
+    if transcendental {
+      symbols.extend(["__bid128_exp", "__bid128_log", "__bid128_pow"]);
+    }
+  }
+  if cfg!(feature = "dpd") {
+    symbols.extend(["__bid_to_dpd128", "__bid_dpd_to_bid128"]);
+  }
+  symbols
+}
+
+/// Prebuilt library linked instead of the vendored sources.
+struct SystemLibrary {
+  /// Path to the library file and the kind of link, `None` when the file was not found.
+  file: Option<(PathBuf, &'static str)>,
+}
+
+/// Returns the file name and the kind of link for candidate names of the library.
+fn library_files(name: &str) -> [(String, &'static str); 4] {
+  [
+    (format!("lib{name}.a"), "static"),
+    (format!("{name}.lib"), "static"),
+    (format!("lib{name}.so"), "dylib"),
+    (format!("lib{name}.dylib"), "dylib"),
+  ]
+}
+
+/// Searches the directories for the library, returns the path to the library file and the kind of link.
+fn find_library(dirs: &[PathBuf], name: &str) -> Option<(PathBuf, &'static str)> {
+  dirs
+    .iter()
+    .flat_map(|dir| library_files(name).map(|(file, kind)| (dir.join(file), kind)))
+    .find(|(path, _)| path.is_file())
+}
+
+/// Links a prebuilt library instead of building the vendored sources.
+///
+/// The library is taken from the directory set in `DFP_LIB_DIR` environment variable,
+/// or when the variable is not set and feature `system-lib` is enabled, it is located using `pkg-config`.
+/// The name of the library is `bid` (like `libbid.a` built by Intel makefiles),
+/// unless another name is set in `DFP_LIB_NAME` environment variable.
+/// Returns `None` when the vendored sources should be built.
+fn link_system_library() -> Option<SystemLibrary> {
+  let name = std::env::var("DFP_LIB_NAME").unwrap_or_else(|_| "bid".to_string());
+  if let Some(dir) = std::env::var_os("DFP_LIB_DIR") {
+    let dir = PathBuf::from(dir);
+    let (path, kind) = find_library(std::slice::from_ref(&dir), &name).unwrap_or_else(|| panic!("library `{name}` not found in DFP_LIB_DIR={}", dir.display()));
+    println!("cargo:rustc-link-search=native={}", dir.display());
+    println!("cargo:rustc-link-lib={kind}={name}");
+    return Some(SystemLibrary { file: Some((path, kind)) });
+  }
+  probe_library(&name)
+}
+
+/// Locates the library using `pkg-config`, pkg-config emits the instructions for linking the library.
+#[cfg(feature = "system-lib")]
+fn probe_library(name: &str) -> Option<SystemLibrary> {
+  let library = pkg_config::Config::new()
+    .probe(name)
+    .unwrap_or_else(|e| panic!("library `{name}` not found by pkg-config: {e}"));
+  Some(SystemLibrary {
+    file: find_library(&library.link_paths, name),
+  })
+}
+
+/// Without feature `system-lib` the library is built from vendored sources.
+#[cfg(not(feature = "system-lib"))]
+fn probe_library(_name: &str) -> Option<SystemLibrary> {
+  None
+}
+
+/// Returns the names of symbols defined in the library, or `None` when `nm` could not be run.
+fn defined_symbols(path: &Path, kind: &str) -> Option<Vec<String>> {
+  let mut command = Command::new(std::env::var("NM").unwrap_or_else(|_| "nm".to_string()));
+  command.args(["-g", "--defined-only"]);
+  if kind == "dylib" && target_cfg("OS") != "macos" {
+    command.arg("-D");
+  }
+  let output = command.arg(path).output().ok().filter(|output| output.status.success())?;
+  let prefix = if target_cfg("OS") == "macos" { "_" } else { "" };
+  Some(
+    String::from_utf8_lossy(&output.stdout)
+      .lines()
+      .filter_map(|line| line.split_whitespace().last())
+      .filter_map(|symbol| symbol.strip_prefix(prefix))
+      .map(str::to_string)
+      .collect(),
+  )
+}
+
+/// Verifies that the prebuilt library defines the symbols required by the bindings,
+/// and that it was built with the same global rounding and global exception flags configuration.
+///
+/// Passing arguments by reference does not change the names of symbols,
+/// so the configuration of `call-by-reference` can not be verified this way.
+fn verify_symbols(path: &Path, kind: &str) {
+  let Some(symbols) = defined_symbols(path, kind) else {
+    println!("cargo:warning=running `nm` on {} failed, symbols are not verified", path.display());
+    return;
+  };
+  let defined = |symbol: &str| symbols.iter().any(|s| s == symbol);
+  let missing = required_symbols().into_iter().filter(|symbol| !defined(symbol)).collect::<Vec<_>>();
+  if !missing.is_empty() {
+    panic!("library {} does not define required symbols: {}", path.display(), missing.join(", "));
+  }
+  for (symbol, feature, enabled) in [
+    ("__bid_IDEC_glbround", "global-rounding", cfg!(feature = "global-rounding")),
+    ("__bid_IDEC_glbflags", "global-exception-flags", cfg!(feature = "global-exception-flags")),
+  ] {
+    match (defined(symbol), enabled) {
+      (true, false) => panic!("library {} defines `{symbol}`, enable feature `{feature}`", path.display()),
+      (false, true) => panic!("library {} does not define `{symbol}` required by feature `{feature}`", path.display()),
+      _ => {}
+    }
+  }
+}
 
-#[cfg(target_os = "linux")]
-const OPERATING_SYSTEM: &str = "linux";
-#[cfg(target_os = "windows")]
-const OPERATING_SYSTEM: &str = "win64";
-#[cfg(target_os = "macos")]
-const OPERATING_SYSTEM: &str = "darwin";
+/// Configures compilation for WebAssembly.
+///
+/// Signals are not available in WebAssembly, so the floating-point exceptions are never raised as `SIGFPE`.
+/// When `DFP_WASI_SYSROOT` environment variable points to the sysroot of wasi-sdk, the library is compiled
+/// against its headers, and for `wasm32-unknown-unknown`, which has no C library, wasi-libc is linked too.
+fn configure_wasm(build: &mut cc::Build, os: &str) {
+  build.define("DPML_SIGNAL(p)", Some(""));
+  if let Some(sysroot) = std::env::var_os("DFP_WASI_SYSROOT") {
+    let sysroot = PathBuf::from(sysroot);
+    build.flag(&format!("--sysroot={}", sysroot.display()));
+    if os == "unknown" {
+      // the directory with libraries was renamed in recent versions of wasi-sdk
+      let lib_dir = ["wasm32-wasip1", "wasm32-wasi"]
+        .map(|dir| sysroot.join("lib").join(dir))
+        .into_iter()
+        .find(|dir| dir.is_dir());
+      let lib_dir = lib_dir.unwrap_or_else(|| panic!("wasi-libc not found in DFP_WASI_SYSROOT={}", sysroot.display()));
+      println!("cargo:rustc-link-search=native={}", lib_dir.display());
+      println!("cargo:rustc-link-lib=static=c");
+    }
+  }
+}
+
+/// Configures compilation for musl C library.
+///
+/// The library declares `wchar_t` as `int` unless the guard defined by glibc headers is set,
+/// which conflicts with musl headers on targets where `wchar_t` is `long` or `unsigned`,
+/// so a header taking `wchar_t` from the C library and setting the guard is included first.
+/// Binaries linked against glibc get `libgcc` from the C compiler, while Rust links musl targets
+/// with its own runtime, so the static `libgcc` is linked explicitly when binary conversions
+/// are enabled, it provides conversions between decimal and 128-bit binary floating-point formats.
+fn configure_musl(build: &mut cc::Build, output_dir: &Path) {
+  let header = output_dir.join("musl_wchar.h");
+  std::fs::write(&header, "#include <stddef.h>\n#define _WCHAR_T_DEFINED\n").expect("failed to write musl header");
+  build.flag("-include").flag(header.to_str().unwrap());
+  if cfg!(feature = "binary-conversions") {
+    let output = build.get_compiler().to_command().arg("-print-libgcc-file-name").output();
+    let libgcc = output
+      .ok()
+      .filter(|output| output.status.success())
+      .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim()));
+    match libgcc.filter(|path| path.is_absolute() && path.is_file()) {
+      Some(path) => {
+        let name = path.file_stem().unwrap().to_string_lossy();
+        println!("cargo:rustc-link-search=native={}", path.parent().unwrap().display());
+        println!("cargo:rustc-link-lib=static:-bundle={}", name.strip_prefix("lib").unwrap_or(&name));
+      }
+      None => println!("cargo:warning=libgcc of the C compiler was not found, conversions to binary128 may be unresolved"),
+    }
+  }
+}
+
+/// Applies optimization settings requested by environment variables and features:
+/// - `DFP_OPT_LEVEL` overrides the optimization level of the Cargo profile, e.g. `3` or `s`,
+/// - `DFP_TARGET_CPU` sets the processor the library is optimized for, e.g. `native`,
+/// - feature `gcc-decimal-intrinsics` declares decimal floating-point intrinsics of GCC, requires GCC with `_Decimal` types,
+/// - feature `cross-language-lto` compiles the library to LLVM bitcode, so it can be optimized together with Rust code;
+///   requires clang based on the same LLVM version as `rustc` and building Rust code with
+///   `RUSTFLAGS="-Clinker-plugin-lto -Clinker=clang -Clink-arg=-fuse-ld=lld"`.
+fn optimize(build: &mut cc::Build) {
+  if let Ok(level) = std::env::var("DFP_OPT_LEVEL") {
+    build.opt_level_str(&level);
+  }
+  if let Ok(cpu) = std::env::var("DFP_TARGET_CPU") {
+    if build.get_compiler().is_like_msvc() {
+      println!("cargo:warning=DFP_TARGET_CPU is not supported by MSVC and is ignored");
+    } else {
+      build.flag(&format!("-march={cpu}"));
+    }
+  }
+  if cfg!(feature = "gcc-decimal-intrinsics") {
+    build.define("BID_HAS_GCC_DECIMAL_INTRINSICS", "1");
+  }
+  if cfg!(feature = "cross-language-lto") {
+    if !build.get_compiler().is_like_clang() {
+      panic!("feature `cross-language-lto` requires clang, set CC=clang");
+    }
+    build.flag("-flto=thin");
+  }
+}
 
 fn main() {
+  println!("cargo:rerun-if-changed=build.rs");
+  println!("cargo:rerun-if-changed=IntelRDFPMathLib20U2");
+  println!("cargo:rerun-if-env-changed=DFP_LIB_DIR");
+  println!("cargo:rerun-if-env-changed=DFP_LIB_NAME");
+  println!("cargo:rerun-if-env-changed=DFP_OPT_LEVEL");
+  println!("cargo:rerun-if-env-changed=DFP_TARGET_CPU");
+  println!("cargo:rerun-if-env-changed=DFP_WASI_SYSROOT");
+  // bindings are generated only when rounding modes and status flags are passed by value
+  println!("cargo:rustc-check-cfg=cfg(dfp_by_value)");
+  if !cfg!(any(feature = "call-by-reference", feature = "global-rounding", feature = "global-exception-flags")) {
+    println!("cargo:rustc-cfg=dfp_by_value");
+  }
+  if let Some(library) = link_system_library() {
+    match library.file {
+      Some((path, kind)) => verify_symbols(&path, kind),
+      None => println!("cargo:warning=file of the linked library was not found, symbols are not verified"),
+    }
+    return;
+  }
   let output_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
-  cc::Build::new()
-    .define(ARCHITECTURE, None)
-    .define(OPERATING_SYSTEM, None)
+  let (arch, os, env, endian) = (target_cfg("ARCH"), target_cfg("OS"), target_cfg("ENV"), target_cfg("ENDIAN"));
+  let big_endian = if endian == "big" { "1" } else { "0" };
+  // the library assumes 64-bit `long` on Windows, other 32-bit targets have 32-bit `long`
+  let size_long = if target_cfg("POINTER_WIDTH") == "32" && os != "windows" { "4" } else { "8" };
+  let mut build = cc::Build::new();
+  build
+    .define(architecture(&arch, &endian), None)
+    .define(operating_system(&os), None)
+    .define("BID_BIG_ENDIAN", big_endian)
+    .define("BID_SIZE_LONG", size_long)
     .define("DECIMAL_CALL_BY_REFERENCE", DECIMAL_CALL_BY_REFERENCE)
     .define("DECIMAL_GLOBAL_ROUNDING", DECIMAL_GLOBAL_ROUNDING)
     .define("DECIMAL_GLOBAL_EXCEPTION_FLAGS", DECIMAL_GLOBAL_EXCEPTION_FLAGS)
@@ -74,253 +657,21 @@ fn main() {
     .flag_if_supported("-Wno-maybe-uninitialized")
     .flag_if_supported("-Wno-array-bounds")
     .flag_if_supported("-Wno-constant-conversion")
-    .flag_if_supported("-Wno-sometimes-uninitialized")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_exception.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_four_over_pi.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_bessel.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_bid.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_cbrt.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_erf.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_exp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_int.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_inv_hyper.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_inv_trig.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_lgamma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_log.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_mod.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_ops.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_ops_64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_pow.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_powi.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_sqrt.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/dpml_ux_trig.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/float128/sqrt_tab_t.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_2_str_tables.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_acos.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_acosh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_add.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_asin.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_asinh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_atan2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_atan.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_atanh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_cbrt.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_compare.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_cos.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_cosh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_div.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_erf.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_erfc.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_exp10.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_exp2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_exp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_expm1.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_fdimd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_fma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_fmod.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_frexp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_hypot.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_ldexp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_lgamma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_llquantexpd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_llrintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_llround.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_log10.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_log1p.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_log2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_logb.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_logbd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_log.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_lrintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_lround.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_minmax.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_modf.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_mul.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_nearbyintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_next.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_nexttowardd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_noncomp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_pow.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_quantexpd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_quantize.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_quantumd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_rem.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_round_integral.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_scalb.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_scalbl.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_sin.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_sinh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_sqrt.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_string.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_tan.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_tanh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_tgamma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_to_int16.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_to_int32.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_to_int64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_to_int8.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_to_uint16.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_to_uint32.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_to_uint64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid128_to_uint8.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_acos.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_acosh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_add.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_asin.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_asinh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_atan2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_atan.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_atanh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_cbrt.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_compare.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_cos.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_cosh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_div.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_erf.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_erfc.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_exp10.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_exp2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_exp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_expm1.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_fdimd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_fma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_fmod.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_frexp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_hypot.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_ldexp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_lgamma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_llquantexpd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_llrintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_llround.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_log10.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_log1p.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_log2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_logb.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_logbd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_log.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_lrintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_lround.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_minmax.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_modf.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_mul.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_nearbyintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_next.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_nexttowardd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_noncomp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_pow.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_quantexpd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_quantize.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_quantumd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_rem.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_round_integral.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_scalb.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_scalbl.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_sin.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_sinh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_sqrt.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_string.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_tan.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_tanh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_tgamma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_bid128.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_int16.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_int32.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_int64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_int8.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_uint16.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_uint32.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_uint64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid64_to_uint8.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_acos.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_acosh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_add.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_asin.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_asinh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_atan2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_atan.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_atanh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_cbrt.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_compare.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_cos.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_cosh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_div.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_erf.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_erfc.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_exp10.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_exp2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_exp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_expm1.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_fdimd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_fma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_fmod.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_frexp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_hypot.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_ldexp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_lgamma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_llquantexpd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_llrintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_llround.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_log10.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_log1p.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_log2.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_logb.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_logbd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_log.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_lrintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_lround.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_minmax.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_modf.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_mul.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_nearbyintd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_next.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_nexttowardd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_noncomp.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_pow.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_quantexpd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_quantize.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_quantumd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_rem.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_round_integral.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_scalb.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_scalbl.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_sin.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_sinh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_sqrt.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_string.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_sub.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_tan.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_tanh.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_tgamma.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_bid128.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_bid64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_int16.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_int32.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_int64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_int8.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_uint16.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_uint32.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_uint64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid32_to_uint8.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_binarydecimal.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_convert_data.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_decimal_data.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_decimal_globals.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_dpd.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_feclearexcept.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_fegetexceptflag.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_feraiseexcept.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_fesetexceptflag.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_fetestexcept.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_flag_operations.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_from_int.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/bid_round.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/strtod128.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/strtod32.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/strtod64.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/wcstod128.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/wcstod32.c")
-    .file("IntelRDFPMathLib20U2/LIBRARY/src/wcstod64.c")
+    .flag_if_supported("-Wno-sometimes-uninitialized");
+  if os == "macos" {
+    // global rounding mode and exception flags are thread-local on other platforms,
+    // but the library leaves them shared by all threads on macOS
+    build.define("BID_THREAD", "__thread");
+  }
+  optimize(&mut build);
+  if arch == "wasm32" {
+    configure_wasm(&mut build, &os);
+  }
+  if env == "musl" {
+    configure_musl(&mut build, &output_dir);
+  }
+  build
+    .files(sources().into_iter().map(|source| format!("IntelRDFPMathLib20U2/LIBRARY/{source}")))
     .out_dir(output_dir.join("lib"))
     .compile("dfp-22");
 }
@@ -0,0 +1,54 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+#![no_main]
+
+//! Compares arithmetic operations on 128-bit decimals with the reference model.
+
+use dfp_number_sys::*;
+use dfp_number_sys_fuzz::*;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+enum Operation {
+  Add,
+  Mul,
+  Div,
+  Quantize,
+}
+
+fuzz_target!(|input: (Operation, Operand, Operand, u8)| {
+  let (operation, x, y, mode) = input;
+  let (x, y, rnd) = (x.encode(), y.encode(), rounding(mode));
+  let (a, b) = (Decimal::decode(x), Decimal::decode(y));
+  let (mut actual_flags, mut expected_flags) = (FB_CLEAR, FB_CLEAR);
+  let (name, actual, expected) = match operation {
+    Operation::Add => ("add", bid128_add(x, y, rnd, &mut actual_flags), add(&a, &b, rnd, &mut expected_flags)),
+    Operation::Mul => ("mul", bid128_mul(x, y, rnd, &mut actual_flags), mul(&a, &b, rnd, &mut expected_flags)),
+    Operation::Div => ("div", bid128_div(x, y, rnd, &mut actual_flags), div(&a, &b, rnd, &mut expected_flags)),
+    Operation::Quantize => ("quantize", bid128_quantize(x, y, rnd, &mut actual_flags), quantize(&a, &b, rnd, &mut expected_flags)),
+  };
+  check(name, &[x, y], actual, actual_flags, &expected, expected_flags);
+});
@@ -0,0 +1,64 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+#![no_main]
+
+//! Compares conversions of strings to 128-bit decimals with the reference model.
+
+use dfp_number_sys::*;
+use dfp_number_sys_fuzz::*;
+use libfuzzer_sys::fuzz_target;
+use num_bigint::BigUint;
+
+fuzz_target!(|input: (Number, u8, &str)| {
+  let (number, mode, raw) = input;
+  let rnd = rounding(mode);
+  // Arbitrary text must never crash the parser.
+  let _ = bid128_from_string(raw, rnd, &mut FB_CLEAR.clone());
+  let (text, exact) = number.build();
+  let (mut actual_flags, mut expected_flags) = (FB_CLEAR, FB_CLEAR);
+  let actual = bid128_from_string(&text, rnd, &mut actual_flags);
+  // Numbers longer than the conversion buffer after removing redundant leading zeros are rejected.
+  let unsigned = text.trim_start_matches(['+', '-']);
+  let zeros = unsigned.as_bytes().windows(2).take_while(|pair| pair[0] == b'0' && pair[1].is_ascii_digit()).count();
+  if text.len() - zeros >= 128 {
+    let expected = Decimal::NaN {
+      negative: false,
+      signaling: false,
+    };
+    check(&format!("from_string {text:?}"), &[], actual, actual_flags, &expected, FB_INVALID);
+    return;
+  }
+  // Known deviation: the library rounds a zero with an exponent below the minimum like a tiny nonzero value.
+  let exact = match exact {
+    Decimal::Finite { negative, exponent, .. } if exponent < MIN_EXPONENT && exact.is_zero() => Decimal::Finite {
+      negative,
+      coefficient: BigUint::from(1_u32),
+      exponent: MIN_EXPONENT - 2,
+    },
+    other => other,
+  };
+  let expected = from_exact(&exact, rnd, &mut expected_flags);
+  check(&format!("from_string {text:?}"), &[], actual, actual_flags, &expected, expected_flags);
+});
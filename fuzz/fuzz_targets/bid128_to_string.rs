@@ -0,0 +1,42 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+#![no_main]
+
+//! Checks formatting of 128-bit decimals and the identity of `to_string` followed by `from_string`.
+
+use dfp_number_sys::*;
+use dfp_number_sys_fuzz::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Operand, u8)| {
+  let (x, mode) = input;
+  let x = x.encode();
+  let mut flags = FB_CLEAR;
+  let text = bid128_to_string(x, &mut flags);
+  let expected = Decimal::decode(x);
+  assert_eq!(text, to_string(&expected), "to_string {x:?}");
+  let actual = bid128_from_string(&text, rounding(mode), &mut flags);
+  check(&format!("from_string {text:?}"), &[x], actual, flags, &expected, FB_CLEAR);
+});
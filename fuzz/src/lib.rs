@@ -0,0 +1,474 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Reference model of 128-bit decimals
+//!
+//! Values are kept exact as arbitrary precision integers and rounded only once
+//! to the format of 128-bit decimals, so the model shares no code nor algorithms
+//! with the library and its results can be compared bit by bit.
+//! Payloads of NaNs are not modeled, only their sign and kind are compared.
+
+use dfp_number_sys::*;
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+
+/// Number of digits in the coefficient.
+pub const PRECISION: u64 = 34;
+
+/// Minimum exponent of the coefficient.
+pub const MIN_EXPONENT: i64 = -6176;
+
+/// Maximum exponent of the coefficient.
+pub const MAX_EXPONENT: i64 = 6111;
+
+/// Minimum adjusted exponent of normal numbers.
+const MIN_NORMAL_EXPONENT: i64 = -6143;
+
+/// Exact decimal value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decimal {
+  Finite { negative: bool, coefficient: BigUint, exponent: i64 },
+  Infinite(bool),
+  NaN { negative: bool, signaling: bool },
+}
+
+impl Decimal {
+  /// Decodes a 128-bit decimal, non-canonical coefficients are decoded as zero.
+  pub fn decode(x: BID128) -> Self {
+    let (hi, lo) = bid128_to_words(x);
+    let negative = hi >> 63 == 1;
+    if hi & 0x7C00_0000_0000_0000 == 0x7C00_0000_0000_0000 {
+      return Decimal::NaN {
+        negative,
+        signaling: hi & 0x7E00_0000_0000_0000 == 0x7E00_0000_0000_0000,
+      };
+    }
+    if hi & 0x7800_0000_0000_0000 == 0x7800_0000_0000_0000 {
+      return Decimal::Infinite(negative);
+    }
+    let (biased, coefficient) = if hi & 0x6000_0000_0000_0000 == 0x6000_0000_0000_0000 {
+      ((hi >> 47) & 0x3FFF, 0)
+    } else {
+      ((hi >> 49) & 0x3FFF, ((hi & 0x0001_FFFF_FFFF_FFFF) as u128) << 64 | lo as u128)
+    };
+    let coefficient = BigUint::from(coefficient);
+    Decimal::Finite {
+      negative,
+      coefficient: if coefficient < pow10(PRECISION) { coefficient } else { BigUint::zero() },
+      exponent: biased as i64 + MIN_EXPONENT,
+    }
+  }
+
+  /// Returns the value with the opposite sign.
+  pub fn negate(self) -> Self {
+    match self {
+      Decimal::Finite { negative, coefficient, exponent } => Decimal::Finite {
+        negative: !negative,
+        coefficient,
+        exponent,
+      },
+      Decimal::Infinite(negative) => Decimal::Infinite(!negative),
+      Decimal::NaN { negative, signaling } => Decimal::NaN { negative: !negative, signaling },
+    }
+  }
+
+  /// Returns `true` when the value is a NaN.
+  pub fn is_nan(&self) -> bool {
+    matches!(self, Decimal::NaN { .. })
+  }
+
+  /// Returns `true` when the value is a finite zero.
+  pub fn is_zero(&self) -> bool {
+    matches!(self, Decimal::Finite { coefficient, .. } if coefficient.is_zero())
+  }
+}
+
+/// Operand of a fuzzed operation, either raw bits or a value built from its parts.
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum Operand {
+  Bits(u64, u64),
+  Finite(bool, u128, u16),
+  Small(bool, u32, i8),
+  Infinite(bool),
+  NaN(bool, bool),
+}
+
+impl Operand {
+  /// Encodes the operand as a 128-bit decimal.
+  pub fn encode(self) -> BID128 {
+    let finite = |negative: bool, coefficient: u128, biased: u64| {
+      let coefficient = coefficient % 10_u128.pow(PRECISION as u32);
+      bid128_from_words((negative as u64) << 63 | biased << 49 | (coefficient >> 64) as u64, coefficient as u64)
+    };
+    match self {
+      Operand::Bits(hi, lo) => bid128_from_words(hi, lo),
+      Operand::Finite(negative, coefficient, biased) => finite(negative, coefficient, biased as u64 % 12288),
+      Operand::Small(negative, coefficient, exponent) => finite(negative, coefficient as u128, (exponent as i64 / 4 - MIN_EXPONENT) as u64),
+      Operand::Infinite(negative) => bid128_from_words((negative as u64) << 63 | 0x7800_0000_0000_0000, 0),
+      Operand::NaN(negative, signaling) => bid128_from_words((negative as u64) << 63 | if signaling { 0x7E00_0000_0000_0000 } else { 0x7C00_0000_0000_0000 }, 0),
+    }
+  }
+}
+
+/// Rounding mode selected by the fuzzer.
+pub fn rounding(mode: u8) -> u32 {
+  [RM_NEAREST_EVEN, RM_DOWNWARD, RM_UPWARD, RM_TOWARD_ZERO, RM_NEAREST_AWAY][mode as usize % 5]
+}
+
+fn pow10(n: u64) -> BigUint {
+  BigUint::from(10_u32).pow(n as u32)
+}
+
+fn digits(c: &BigUint) -> u64 {
+  if c.is_zero() {
+    1
+  } else {
+    c.to_string().len() as u64
+  }
+}
+
+fn nan(negative: bool) -> Decimal {
+  Decimal::NaN { negative, signaling: false }
+}
+
+fn invalid(flags: &mut u32) -> Decimal {
+  *flags |= FB_INVALID;
+  nan(false)
+}
+
+/// Returns a quiet NaN when any of the operands is a NaN.
+fn propagate(operands: &[&Decimal], flags: &mut u32) -> Option<Decimal> {
+  if operands.iter().any(|x| matches!(x, Decimal::NaN { signaling: true, .. })) {
+    *flags |= FB_INVALID;
+  }
+  operands.iter().find_map(|x| match x {
+    Decimal::NaN { negative, .. } => Some(nan(*negative)),
+    _ => None,
+  })
+}
+
+/// Drops `shift` trailing digits of the coefficient rounding the result,
+/// returns the rounded coefficient and `true` when the result is inexact.
+fn shift_round(negative: bool, coefficient: &BigUint, shift: u64, round: u32) -> (BigUint, bool) {
+  if shift == 0 {
+    return (coefficient.clone(), false);
+  }
+  if shift > digits(coefficient) + 1 {
+    let up = !coefficient.is_zero() && ((round == RM_DOWNWARD && negative) || (round == RM_UPWARD && !negative));
+    return (if up { BigUint::one() } else { BigUint::zero() }, !coefficient.is_zero());
+  }
+  let divisor = pow10(shift);
+  let quotient = coefficient / &divisor;
+  let remainder = coefficient % &divisor;
+  if remainder.is_zero() {
+    return (quotient, false);
+  }
+  let half = (&remainder * 2_u32).cmp(&divisor);
+  let odd = (&quotient % 2_u32).is_one();
+  let up = match round {
+    RM_NEAREST_EVEN => half == Ordering::Greater || (half == Ordering::Equal && odd),
+    RM_NEAREST_AWAY => half != Ordering::Less,
+    RM_DOWNWARD => negative,
+    RM_UPWARD => !negative,
+    _ => false,
+  };
+  (if up { quotient + 1_u32 } else { quotient }, true)
+}
+
+/// Rounds the exact value to the format, the exponent is the preferred one.
+pub fn round(negative: bool, coefficient: BigUint, exponent: i64, round: u32, flags: &mut u32) -> Decimal {
+  if coefficient.is_zero() {
+    return Decimal::Finite {
+      negative,
+      coefficient,
+      exponent: exponent.clamp(MIN_EXPONENT, MAX_EXPONENT),
+    };
+  }
+  let length = digits(&coefficient) as i64;
+  let tiny = length - 1 + exponent < MIN_NORMAL_EXPONENT;
+  let shift = (length - PRECISION as i64).max(MIN_EXPONENT - exponent).max(0);
+  let (mut coefficient, inexact) = shift_round(negative, &coefficient, shift as u64, round);
+  let mut exponent = exponent + shift;
+  if coefficient == pow10(PRECISION) {
+    coefficient = pow10(PRECISION - 1);
+    exponent += 1;
+  }
+  if inexact {
+    *flags |= FB_INEXACT;
+    if tiny {
+      *flags |= FB_UNDERFLOW;
+    }
+  }
+  if exponent > MAX_EXPONENT {
+    let padding = (exponent - MAX_EXPONENT) as u64;
+    if !coefficient.is_zero() && digits(&coefficient) + padding > PRECISION {
+      *flags |= FB_OVERFLOW | FB_INEXACT;
+      let infinite = match round {
+        RM_TOWARD_ZERO => false,
+        RM_DOWNWARD => negative,
+        RM_UPWARD => !negative,
+        _ => true,
+      };
+      if infinite {
+        return Decimal::Infinite(negative);
+      }
+      return Decimal::Finite {
+        negative,
+        coefficient: pow10(PRECISION) - 1_u32,
+        exponent: MAX_EXPONENT,
+      };
+    }
+    coefficient *= pow10(padding);
+    exponent = MAX_EXPONENT;
+  }
+  Decimal::Finite { negative, coefficient, exponent }
+}
+
+/// Adds two values.
+pub fn add(x: &Decimal, y: &Decimal, rnd: u32, flags: &mut u32) -> Decimal {
+  if let Some(result) = propagate(&[x, y], flags) {
+    return result;
+  }
+  match (x, y) {
+    (Decimal::Infinite(a), Decimal::Infinite(b)) if a != b => invalid(flags),
+    (Decimal::Infinite(a), _) | (_, Decimal::Infinite(a)) => Decimal::Infinite(*a),
+    (
+      Decimal::Finite {
+        negative: nx,
+        coefficient: cx,
+        exponent: ex,
+      },
+      Decimal::Finite {
+        negative: ny,
+        coefficient: cy,
+        exponent: ey,
+      },
+    ) => {
+      let exponent = *ex.min(ey);
+      let a = cx * pow10((ex - exponent) as u64);
+      let b = cy * pow10((ey - exponent) as u64);
+      let (negative, coefficient) = if nx == ny {
+        (*nx, a + b)
+      } else {
+        match a.cmp(&b) {
+          Ordering::Greater => (*nx, a - b),
+          Ordering::Less => (*ny, b - a),
+          Ordering::Equal => (rnd == RM_DOWNWARD, BigUint::zero()),
+        }
+      };
+      round(negative, coefficient, exponent, rnd, flags)
+    }
+    _ => unreachable!(),
+  }
+}
+
+/// Multiplies two values.
+pub fn mul(x: &Decimal, y: &Decimal, rnd: u32, flags: &mut u32) -> Decimal {
+  if let Some(result) = propagate(&[x, y], flags) {
+    return result;
+  }
+  match (x, y) {
+    (Decimal::Infinite(_), y) | (y, Decimal::Infinite(_)) if y.is_zero() => invalid(flags),
+    (Decimal::Infinite(a), Decimal::Infinite(b)) => Decimal::Infinite(a != b),
+    (Decimal::Infinite(a), Decimal::Finite { negative: b, .. }) | (Decimal::Finite { negative: b, .. }, Decimal::Infinite(a)) => Decimal::Infinite(a != b),
+    (
+      Decimal::Finite {
+        negative: nx,
+        coefficient: cx,
+        exponent: ex,
+      },
+      Decimal::Finite {
+        negative: ny,
+        coefficient: cy,
+        exponent: ey,
+      },
+    ) => round(nx != ny, cx * cy, ex + ey, rnd, flags),
+    _ => unreachable!(),
+  }
+}
+
+/// Divides two values.
+pub fn div(x: &Decimal, y: &Decimal, rnd: u32, flags: &mut u32) -> Decimal {
+  if let Some(result) = propagate(&[x, y], flags) {
+    return result;
+  }
+  match (x, y) {
+    (Decimal::Infinite(_), Decimal::Infinite(_)) => invalid(flags),
+    (Decimal::Infinite(a), Decimal::Finite { negative: b, .. }) => Decimal::Infinite(a != b),
+    (Decimal::Finite { negative: a, .. }, Decimal::Infinite(b)) => Decimal::Finite {
+      negative: a != b,
+      coefficient: BigUint::zero(),
+      exponent: MIN_EXPONENT,
+    },
+    (
+      Decimal::Finite {
+        negative: nx,
+        coefficient: cx,
+        exponent: ex,
+      },
+      Decimal::Finite {
+        negative: ny,
+        coefficient: cy,
+        exponent: ey,
+      },
+    ) => {
+      let negative = nx != ny;
+      let ideal = ex - ey;
+      if cy.is_zero() {
+        if cx.is_zero() {
+          return invalid(flags);
+        }
+        *flags |= FB_ZERO_DIVIDE;
+        return Decimal::Infinite(negative);
+      }
+      if cx.is_zero() {
+        return round(negative, BigUint::zero(), ideal, rnd, flags);
+      }
+      let shift = (PRECISION + 1 + digits(cy)).saturating_sub(digits(cx));
+      let dividend = cx * pow10(shift);
+      let mut quotient = &dividend / cy;
+      let mut exponent = ideal - shift as i64;
+      if (dividend % cy).is_zero() {
+        while exponent < ideal && (&quotient % 10_u32).is_zero() {
+          quotient /= 10_u32;
+          exponent += 1;
+        }
+      } else {
+        quotient = quotient * 10_u32 + 1_u32;
+        exponent -= 1;
+      }
+      round(negative, quotient, exponent, rnd, flags)
+    }
+    _ => unreachable!(),
+  }
+}
+
+/// Sets the exponent of the first value to the exponent of the second value.
+pub fn quantize(x: &Decimal, y: &Decimal, rnd: u32, flags: &mut u32) -> Decimal {
+  if let Some(result) = propagate(&[x, y], flags) {
+    return result;
+  }
+  match (x, y) {
+    (Decimal::Infinite(a), Decimal::Infinite(_)) => Decimal::Infinite(*a),
+    (Decimal::Infinite(_), _) | (_, Decimal::Infinite(_)) => invalid(flags),
+    (Decimal::Finite { negative, coefficient, exponent }, Decimal::Finite { exponent: target, .. }) => {
+      let coefficient = if exponent >= target {
+        let padding = (exponent - target) as u64;
+        if !coefficient.is_zero() && digits(coefficient) + padding > PRECISION {
+          return invalid(flags);
+        }
+        coefficient * pow10(padding)
+      } else {
+        let (coefficient, inexact) = shift_round(*negative, coefficient, (target - exponent) as u64, rnd);
+        if coefficient >= pow10(PRECISION) {
+          return invalid(flags);
+        }
+        if inexact {
+          *flags |= FB_INEXACT;
+        }
+        coefficient
+      };
+      Decimal::Finite {
+        negative: *negative,
+        coefficient,
+        exponent: *target,
+      }
+    }
+    _ => unreachable!(),
+  }
+}
+
+/// Decimal number in the scientific notation, built by the fuzzer.
+#[derive(Arbitrary, Debug)]
+pub struct Number {
+  sign: Option<bool>,
+  integer: Vec<u8>,
+  fraction: Option<Vec<u8>>,
+  exponent: Option<(bool, i16)>,
+}
+
+impl Number {
+  /// Returns the text of the number and its exact value.
+  pub fn build(&self) -> (String, Decimal) {
+    let digit = |d: &u8| (b'0' + d % 10) as char;
+    let mut text = String::new();
+    match self.sign {
+      Some(true) => text.push('-'),
+      Some(false) => text.push('+'),
+      None => {}
+    }
+    let mut digits = self.integer.iter().map(digit).collect::<String>();
+    if digits.is_empty() && self.fraction.as_ref().is_none_or(Vec::is_empty) {
+      digits.push('0');
+    }
+    text.push_str(&digits);
+    let mut exponent = 0;
+    if let Some(fraction) = &self.fraction {
+      let fraction = fraction.iter().map(digit).collect::<String>();
+      text.push('.');
+      text.push_str(&fraction);
+      digits.push_str(&fraction);
+      exponent -= fraction.len() as i64;
+    }
+    if let Some((upper, value)) = self.exponent {
+      text.push(if upper { 'E' } else { 'e' });
+      text.push_str(&value.to_string());
+      exponent += value as i64;
+    }
+    let coefficient = digits.parse::<BigUint>().unwrap();
+    let negative = self.sign == Some(true);
+    (text, Decimal::Finite { negative, coefficient, exponent })
+  }
+}
+
+/// Converts an exact value to the format.
+pub fn from_exact(x: &Decimal, rnd: u32, flags: &mut u32) -> Decimal {
+  match x {
+    Decimal::Finite { negative, coefficient, exponent } => round(*negative, coefficient.clone(), *exponent, rnd, flags),
+    other => other.clone(),
+  }
+}
+
+/// Formats the value the way the library does.
+pub fn to_string(x: &Decimal) -> String {
+  match x {
+    Decimal::Finite { negative, coefficient, exponent } => format!("{}{}E{:+}", if *negative { '-' } else { '+' }, coefficient, exponent),
+    Decimal::Infinite(negative) => format!("{}Inf", if *negative { '-' } else { '+' }),
+    Decimal::NaN { negative, signaling } => format!("{}{}NaN", if *negative { '-' } else { '+' }, if *signaling { "S" } else { "" }),
+  }
+}
+
+/// Checks the result of the library against the reference, NaNs are compared by their kind only.
+pub fn check(operation: &str, operands: &[BID128], actual: BID128, actual_flags: u32, expected: &Decimal, expected_flags: u32) {
+  let decoded = Decimal::decode(actual);
+  let same = if expected.is_nan() { decoded.is_nan() } else { decoded == *expected };
+  assert!(
+    same && actual_flags == expected_flags,
+    "{operation} {operands:?}: expected {} flags {expected_flags:02x}, actual {} flags {actual_flags:02x}",
+    to_string(expected),
+    to_string(&decoded)
+  );
+}
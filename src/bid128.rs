@@ -23,3 +23,216 @@
  */
 
 //! Common definitions for binary encoded decimal 128
+
+use crate::BID128;
+#[cfg(any(feature = "std", all(dfp_by_value, feature = "bid128")))]
+use crate::RM_DOWNWARD;
+#[cfg(feature = "std")]
+use crate::{RM_NEAREST_AWAY, RM_NEAREST_EVEN, RM_UPWARD};
+
+/// Maximum length of 128-bit decimal converted to string, including the terminating NUL character,
+/// e.g. `-9999999999999999999999999999999999E-6176`.
+pub const BID128_STRING_LENGTH: usize = 42;
+
+/// Index of the word holding the most significant bits of [BID128].
+#[cfg(target_endian = "little")]
+pub(crate) const HIGH: usize = 1;
+/// Index of the word holding the most significant bits of [BID128].
+#[cfg(target_endian = "big")]
+pub(crate) const HIGH: usize = 0;
+/// Index of the word holding the least significant bits of [BID128].
+#[cfg(target_endian = "little")]
+pub(crate) const LOW: usize = 0;
+/// Index of the word holding the least significant bits of [BID128].
+#[cfg(target_endian = "big")]
+pub(crate) const LOW: usize = 1;
+/// Bias of the exponent in 128-bit decimal.
+pub(crate) const EXPONENT_BIAS: i32 = 6176;
+/// Minimum exponent of 128-bit decimal.
+pub(crate) const MIN_EXPONENT: i32 = -EXPONENT_BIAS;
+/// Maximum exponent of 128-bit decimal.
+pub(crate) const MAX_EXPONENT: i32 = 12287 - EXPONENT_BIAS;
+/// Maximum value of the coefficient in canonical 128-bit decimal.
+pub(crate) const MAX_COEFFICIENT: u128 = 9_999_999_999_999_999_999_999_999_999_999_999;
+
+/// Creates 128-bit decimal from its most and least significant words,
+/// independently of the order of words in memory on the target platform.
+pub fn bid128_from_words(hi: u64, lo: u64) -> BID128 {
+  let mut x = BID128 { w: [0; 2] };
+  x.w[HIGH] = hi;
+  x.w[LOW] = lo;
+  x
+}
+
+/// Returns the most and least significant words of 128-bit decimal,
+/// independently of the order of words in memory on the target platform.
+pub fn bid128_to_words(x: BID128) -> (u64, u64) {
+  (x.w[HIGH], x.w[LOW])
+}
+
+/// Decoded 128-bit decimal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Decoded {
+  /// Finite value: sign (`true` when negative), coefficient and exponent.
+  Finite(bool, u128, i32),
+  /// Infinity with sign.
+  Infinite(bool),
+  /// Quiet or signaling NaN with sign.
+  NaN(bool),
+}
+
+/// Decodes the sign, coefficient and exponent of the 128-bit decimal.
+/// Non-canonical coefficients are decoded as zero, like in the Intel library.
+pub(crate) fn decode(x: BID128) -> Decoded {
+  let hi = x.w[HIGH];
+  let sign = hi & 0x8000_0000_0000_0000 != 0;
+  if hi & 0x7C00_0000_0000_0000 == 0x7C00_0000_0000_0000 {
+    return Decoded::NaN(sign);
+  }
+  if hi & 0x7800_0000_0000_0000 == 0x7800_0000_0000_0000 {
+    return Decoded::Infinite(sign);
+  }
+  if hi & 0x6000_0000_0000_0000 == 0x6000_0000_0000_0000 {
+    // the implicit `100` prefix always produces a coefficient greater than the maximum
+    let exponent = ((hi >> 47) & 0x3FFF) as i32 - EXPONENT_BIAS;
+    return Decoded::Finite(sign, 0, exponent);
+  }
+  let exponent = ((hi >> 49) & 0x3FFF) as i32 - EXPONENT_BIAS;
+  let coefficient = ((hi & 0x0001_FFFF_FFFF_FFFF) as u128) << 64 | x.w[LOW] as u128;
+  if coefficient > MAX_COEFFICIENT {
+    Decoded::Finite(sign, 0, exponent)
+  } else {
+    Decoded::Finite(sign, coefficient, exponent)
+  }
+}
+
+/// Encodes the sign, coefficient and exponent as canonical 128-bit decimal.
+/// The coefficient and the exponent must be in range.
+pub(crate) fn encode(sign: bool, coefficient: u128, exponent: i32) -> BID128 {
+  let hi = (sign as u64) << 63 | ((exponent + EXPONENT_BIAS) as u64) << 49 | (coefficient >> 64) as u64;
+  let mut x = BID128 { w: [0; 2] };
+  x.w[HIGH] = hi;
+  x.w[LOW] = coefficient as u64;
+  x
+}
+
+/// Returns the value with reversed sign, without calling the Intel library.
+#[cfg(all(dfp_by_value, feature = "bid128"))]
+pub(crate) fn negate(mut x: BID128) -> BID128 {
+  x.w[HIGH] ^= 0x8000_0000_0000_0000;
+  x
+}
+
+/// Powers of ten used for aligning coefficients of small decimals, `10^19 * u64::MAX` exceeds the maximum coefficient.
+#[cfg(all(dfp_by_value, feature = "bid128"))]
+const POWERS_OF_TEN: [u128; 19] = {
+  let mut powers = [1_u128; 19];
+  let mut i = 1;
+  while i < powers.len() {
+    powers[i] = powers[i - 1] * 10;
+    i += 1;
+  }
+  powers
+};
+
+/// Decodes the sign, coefficient and exponent of a finite decimal
+/// with canonical coefficient that fits in 64 bits.
+#[cfg(all(dfp_by_value, feature = "bid128"))]
+fn decode_small(x: BID128) -> Option<(bool, u64, i32)> {
+  let hi = x.w[HIGH];
+  // steering bits `11` are set in infinities, NaNs and in large non-canonical coefficients
+  if hi & 0x6000_0000_0000_0000 == 0x6000_0000_0000_0000 || hi & 0x0001_FFFF_FFFF_FFFF != 0 {
+    return None;
+  }
+  Some((hi & 0x8000_0000_0000_0000 != 0, x.w[LOW], ((hi >> 49) & 0x3FFF) as i32 - EXPONENT_BIAS))
+}
+
+/// Returns the coefficient multiplied by `10^count` when the result fits in 128-bit decimal.
+#[cfg(all(dfp_by_value, feature = "bid128"))]
+fn scale(coefficient: u64, count: u32) -> Option<u128> {
+  if coefficient == 0 {
+    return Some(0);
+  }
+  let scaled = (coefficient as u128) * *POWERS_OF_TEN.get(count as usize)?;
+  (scaled <= MAX_COEFFICIENT).then_some(scaled)
+}
+
+/// Returns the sum of two finite values with small coefficients when it can be represented exactly,
+/// so no rounding is needed and no exception flags are raised.
+/// The result is identical to the one calculated by the Intel library,
+/// including the preferred exponent and the sign of zero.
+#[cfg(all(dfp_by_value, feature = "bid128"))]
+pub(crate) fn add_exact(x: BID128, y: BID128, round: u32) -> Option<BID128> {
+  let (sign_x, coefficient_x, exponent_x) = decode_small(x)?;
+  let (sign_y, coefficient_y, exponent_y) = decode_small(y)?;
+  let exponent = exponent_x.min(exponent_y);
+  let coefficient_x = scale(coefficient_x, exponent_x.abs_diff(exponent))?;
+  let coefficient_y = scale(coefficient_y, exponent_y.abs_diff(exponent))?;
+  let (sign, coefficient) = if sign_x == sign_y {
+    (sign_x, coefficient_x + coefficient_y)
+  } else if coefficient_x > coefficient_y {
+    (sign_x, coefficient_x - coefficient_y)
+  } else if coefficient_x < coefficient_y {
+    (sign_y, coefficient_y - coefficient_x)
+  } else {
+    // exact zero sum of operands with opposite signs is negative only when rounding downward
+    (round == RM_DOWNWARD, 0)
+  };
+  if coefficient > MAX_COEFFICIENT {
+    return None;
+  }
+  Some(encode(sign, coefficient, exponent))
+}
+
+/// Returns the product of two finite values with small coefficients when it can be represented exactly,
+/// so no rounding is needed and no exception flags are raised.
+/// The result is identical to the one calculated by the Intel library.
+#[cfg(all(dfp_by_value, feature = "bid128"))]
+pub(crate) fn mul_exact(x: BID128, y: BID128) -> Option<BID128> {
+  let (sign_x, coefficient_x, exponent_x) = decode_small(x)?;
+  let (sign_y, coefficient_y, exponent_y) = decode_small(y)?;
+  let coefficient = coefficient_x as u128 * coefficient_y as u128;
+  let exponent = exponent_x + exponent_y;
+  if coefficient > MAX_COEFFICIENT || !(MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+    return None;
+  }
+  Some(encode(sign_x != sign_y, coefficient, exponent))
+}
+
+/// Returns the number of decimal digits in `n`, zero has one digit.
+#[cfg(feature = "std")]
+pub(crate) fn digits(n: u128) -> u32 {
+  if n == 0 {
+    1
+  } else {
+    n.ilog10() + 1
+  }
+}
+
+/// Removes `count` least significant digits from the coefficient,
+/// rounding the result according to the specified rounding mode.
+#[cfg(feature = "std")]
+pub(crate) fn round_digits(coefficient: u128, count: u32, negative: bool, round: u32) -> u128 {
+  if count == 0 {
+    return coefficient;
+  }
+  let (quotient, remainder, half) = if count > 38 {
+    // the coefficient is always below the half of 10^count
+    (0, coefficient, u128::MAX)
+  } else {
+    let divisor = 10_u128.pow(count);
+    (coefficient / divisor, coefficient % divisor, divisor / 2)
+  };
+  let increment = match round {
+    RM_NEAREST_EVEN => remainder > half || (remainder == half && quotient & 1 == 1),
+    RM_NEAREST_AWAY => remainder >= half,
+    RM_DOWNWARD => remainder > 0 && negative,
+    RM_UPWARD => remainder > 0 && !negative,
+    _ => false,
+  };
+  if increment {
+    quotient + 1
+  } else {
+    quotient
+  }
+}
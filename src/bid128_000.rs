@@ -27,8 +27,12 @@
 //! - 0 rounding mode passed as argument,
 //! - 0 pointer to status flags passed as argument.
 
-use crate::BID128;
-use libc::{c_char, c_int, c_longlong, c_uint, c_ulonglong};
+use crate::bid128::{add_exact, mul_exact, negate};
+use crate::common::{copy_to_buffer, FROM_BYTES_BUFFER_LENGTH};
+use crate::{BID128, BID128_STRING_LENGTH, BID32, BID64, FB_INVALID, RM_NEAREST_EVEN};
+use core::ffi::{c_char, c_int, c_longlong, c_uint, c_ulonglong};
+use core::fmt;
+#[cfg(feature = "std")]
 use std::ffi::{CStr, CString};
 
 #[rustfmt::skip]
@@ -37,7 +41,9 @@ extern "C" {
   fn __bid128_add(x: BID128, y: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
   fn __bid128_copy(x: BID128) -> BID128;
   fn __bid128_div(x: BID128, y: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  #[cfg(feature = "transcendental")]
   fn __bid128_exp(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_fma(x: BID128, y: BID128, z: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
   fn __bid128_frexp(x: BID128, exp: *mut c_int) -> BID128;
   fn __bid128_from_int32(x: c_int) -> BID128;
   fn __bid128_from_int64(x: c_longlong) -> BID128;
@@ -50,13 +56,15 @@ extern "C" {
   fn __bid128_isInf(x: BID128) -> c_int;
   fn __bid128_isSigned(x: BID128) -> c_int;
   fn __bid128_isZero(x: BID128) -> c_int;
+  #[cfg(feature = "transcendental")]
   fn __bid128_log(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
   fn __bid128_maxnum(x: BID128, y: BID128, flags: *mut c_uint) -> BID128;
   fn __bid128_minnum(x: BID128, y: BID128, flags: *mut c_uint) -> BID128;
   fn __bid128_negate(x: BID128) -> BID128;
   fn __bid128_mul(x: BID128, y: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  #[cfg(feature = "transcendental")]
   fn __bid128_pow(x: BID128, y: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
-  fn __bid128_quantexp(x: BID128) -> c_int;
+  fn __bid128_quantexp(x: BID128, flags: *mut c_uint) -> c_int;
   fn __bid128_quantum(x: BID128) -> BID128;
   fn __bid128_quantize(x: BID128, y: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
   fn __bid128_quiet_equal(x: BID128, y: BID128, flags: *mut c_uint) -> c_int;
@@ -71,14 +79,17 @@ extern "C" {
   fn __bid128_round_integral_negative(x: BID128, flags: *mut c_uint) -> BID128;
   fn __bid128_round_integral_positive(x: BID128, flags: *mut c_uint) -> BID128;
   fn __bid128_round_integral_zero(x: BID128, flags: *mut c_uint) -> BID128;
-  fn __bid128_scalbn(x: BID128, n: c_int) -> BID128;
+  fn __bid128_scalbn(x: BID128, n: c_int, round: c_uint, flags: *mut c_uint) -> BID128;
   fn __bid128_sqrt(x: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
   fn __bid128_sub(x: BID128, y: BID128, round: c_uint, flags: *mut c_uint) -> BID128;
+  fn __bid128_to_bid32(x: BID128, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid128_to_bid64(x: BID128, round: c_uint, flags: *mut c_uint) -> BID64;
   fn __bid128_to_int32_int(x: BID128, flags: *mut c_uint) -> c_int;
   fn __bid128_to_uint32_int(x: BID128, flags: *mut c_uint) -> c_uint;
   fn __bid128_to_int64_int(x: BID128, flags: *mut c_uint) -> c_longlong;
   fn __bid128_to_uint64_int(x: BID128, flags: *mut c_uint) -> c_ulonglong;
   fn __bid128_to_string(s: *mut c_char, x: BID128, flags: *mut c_uint);
+  fn __bid_strtod128(s: *const c_char, endptr: *mut *mut c_char) -> BID128;
 }
 
 /// Copies a 128-bit decimal floating-point operand x to a destination in the same format,
@@ -88,7 +99,11 @@ pub fn bid128_abs(x: BID128) -> BID128 {
 }
 
 /// Returns a result of decimal floating-point addition, [Decimal128] + [Decimal128] -> [Decimal128]
+/// Exact sums of finite operands are calculated without calling the Intel library.
 pub fn bid128_add(x: BID128, y: BID128, round: u32, flags: &mut u32) -> BID128 {
+  if let Some(result) = add_exact(x, y, round) {
+    return result;
+  }
   unsafe { __bid128_add(x, y, round, flags) }
 }
 
@@ -103,11 +118,17 @@ pub fn bid128_div(x: BID128, y: BID128, round: u32, flags: &mut u32) -> BID128 {
 }
 
 /// Returns the value of `e` raised to the `x`th power.
+#[cfg(feature = "transcendental")]
 pub fn bid128_exp(x: BID128, round: u32, flags: &mut u32) -> BID128 {
   unsafe { __bid128_exp(x, round, flags) }
 }
 
-///
+/// Returns the value of `x` multiplied by `y` and added to `z`, rounded only once.
+pub fn bid128_fma(x: BID128, y: BID128, z: BID128, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_fma(x, y, z, round, flags) }
+}
+
+/// Splits the value into a fraction in range [0.1, 1) and an integral power of ten.
 pub fn bid128_frexp(x: BID128, exp: &mut i32) -> BID128 {
   unsafe { __bid128_frexp(x, exp) }
 }
@@ -124,9 +145,35 @@ pub fn bid128_from_int64(x: i64) -> BID128 {
 
 /// Converts a decimal floating-point value represented in string format (decimal character sequence)
 /// to 128-bit decimal floating-point format (binary encoding).
+/// Malformed input, including strings with interior NUL characters, is converted to NaN.
+/// The length of the string is limited like in [bid128_from_bytes].
 pub fn bid128_from_string(s: &str, round: u32, flags: &mut u32) -> BID128 {
-  let c_s = CString::new(s).unwrap();
-  unsafe { __bid128_from_string(c_s.as_ptr(), round, flags) }
+  bid128_from_bytes(s.as_bytes(), round, flags)
+}
+
+/// Converts a decimal floating-point value represented as a sequence of ASCII characters,
+/// not terminated with NUL, to 128-bit decimal floating-point format (binary encoding).
+/// Malformed input, including sequences with NUL characters, is converted to NaN.
+/// Sequences are converted without heap allocation, so after skipping leading blanks
+/// and redundant leading zeros they must be shorter than 128 bytes,
+/// longer sequences are converted to NaN and the `Invalid` exception flag is set.
+pub fn bid128_from_bytes(bytes: &[u8], round: u32, flags: &mut u32) -> BID128 {
+  if bytes.contains(&0) {
+    return unsafe { __bid128_from_string(c"NaN".as_ptr(), round, flags) };
+  }
+  let mut buf = [0_u8; FROM_BYTES_BUFFER_LENGTH];
+  if !copy_to_buffer(bytes, &mut buf) {
+    *flags |= FB_INVALID;
+    return unsafe { __bid128_from_string(c"NaN".as_ptr(), round, flags) };
+  }
+  unsafe { __bid128_from_string(buf.as_ptr() as *const c_char, round, flags) }
+}
+
+/// Converts NUL-terminated string to 128-bit decimal floating-point number without limiting its length,
+/// used for input validated before the conversion.
+#[cfg(feature = "std")]
+pub(crate) fn bid128_from_c_str(s: &CStr, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_from_string(s.as_ptr(), round, flags) }
 }
 
 /// Converts 32-bit unsigned integer to 128-bit decimal floating-point number.
@@ -171,6 +218,7 @@ pub fn bid128_is_zero(x: BID128) -> bool {
 }
 
 /// Returns natural logarithm of `x`.
+#[cfg(feature = "transcendental")]
 pub fn bid128_log(x: BID128, round: u32, flags: &mut u32) -> BID128 {
   unsafe { __bid128_log(x, round, flags) }
 }
@@ -197,18 +245,24 @@ pub fn bid128_negate(x: BID128) -> BID128 {
 }
 
 /// Returns s result of decimal floating-point multiplication, [Decimal128] * [Decimal128] -> [Decimal128]
+/// Exact products of finite operands are calculated without calling the Intel library.
 pub fn bid128_mul(x: BID128, y: BID128, round: u32, flags: &mut u32) -> BID128 {
+  if let Some(result) = mul_exact(x, y) {
+    return result;
+  }
   unsafe { __bid128_mul(x, y, round, flags) }
 }
 
 /// Returns decimal floating-point power.
+#[cfg(feature = "transcendental")]
 pub fn bid128_pow(x: BID128, y: BID128, round: u32, flags: &mut u32) -> BID128 {
   unsafe { __bid128_pow(x, y, round, flags) }
 }
 
 /// Returns the quantum of a finite argument as a signed integer value.
-pub fn bid128_quantexp(x: BID128) -> i32 {
-  unsafe { __bid128_quantexp(x) }
+/// If x is infinite or NaN, the result is `i32::MIN` and the `Invalid` exception flag is set.
+pub fn bid128_quantexp(x: BID128, flags: &mut u32) -> i32 {
+  unsafe { __bid128_quantexp(x, flags) }
 }
 
 /// Returns the quantum of a finite argument.
@@ -297,7 +351,14 @@ pub fn bid128_round_integral_zero(x: BID128, flags: &mut u32) -> BID128 {
 
 /// Returns `x * 10^n`.
 pub fn bid128_scalbn(x: BID128, n: i32) -> BID128 {
-  unsafe { __bid128_scalbn(x, n) }
+  let mut flags = 0;
+  unsafe { __bid128_scalbn(x, n, RM_NEAREST_EVEN, &mut flags) }
+}
+
+/// Returns `x * 10^n`, rounded according to the specified rounding mode
+/// when the result does not fit in the range of exponents.
+pub fn bid128_scalbn_round(x: BID128, n: i32, round: u32, flags: &mut u32) -> BID128 {
+  unsafe { __bid128_scalbn(x, n, round, flags) }
 }
 
 /// Returns decimal floating-point square root.
@@ -306,10 +367,26 @@ pub fn bid128_sqrt(x: BID128, round: u32, flags: &mut u32) -> BID128 {
 }
 
 /// Returns a result of decimal floating-point subtraction, [Decimal128] - [Decimal128] -> [Decimal128]
+/// Exact differences of finite operands are calculated without calling the Intel library.
 pub fn bid128_sub(x: BID128, y: BID128, round: u32, flags: &mut u32) -> BID128 {
+  if let Some(result) = add_exact(x, negate(y), round) {
+    return result;
+  }
   unsafe { __bid128_sub(x, y, round, flags) }
 }
 
+/// Converts 128-bit decimal floating-point value to 32-bit decimal floating-point value,
+/// rounded according to the specified rounding mode.
+pub fn bid128_to_bid32(x: BID128, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid128_to_bid32(x, round, flags) }
+}
+
+/// Converts 128-bit decimal floating-point value to 64-bit decimal floating-point value,
+/// rounded according to the specified rounding mode.
+pub fn bid128_to_bid64(x: BID128, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid128_to_bid64(x, round, flags) }
+}
+
 /// Convert 128-bit decimal floating-point value to 32-bit signed integer
 /// with rounding-to-zero mode, inexact exceptions are not signaled.
 pub fn bid128_to_int32_int(x: BID128, flags: &mut u32) -> i32 {
@@ -336,12 +413,67 @@ pub fn bid128_to_uint64_int(x: BID128, flags: &mut u32) -> u64 {
 
 /// Converts 128-bit decimal floating-point value (binary encoding)
 /// to string format (decimal character sequence).
+#[cfg(feature = "std")]
 pub fn bid128_to_string(x: BID128, flags: &mut u32) -> String {
-  let mut buf = [0_u8; 1024];
+  let mut buf = [0_u8; BID128_STRING_LENGTH];
+  bid128_to_str_buf(x, &mut buf, flags).to_string()
+}
+
+/// Converts 128-bit decimal floating-point value (binary encoding)
+/// to string format (decimal character sequence) stored in the provided buffer.
+/// The buffer must be at least [BID128_STRING_LENGTH] bytes long, this is checked at compile time.
+pub fn bid128_to_str_buf<'a, const N: usize>(x: BID128, buf: &'a mut [u8; N], flags: &mut u32) -> &'a str {
+  const { assert!(N >= BID128_STRING_LENGTH, "buffer is too short") };
   unsafe {
     __bid128_to_string(buf.as_mut_ptr() as *mut c_char, x, flags);
-    CStr::from_ptr(buf.as_ptr() as *const c_char).to_string_lossy().into_owned()
   }
+  let len = buf.iter().position(|b| *b == 0).unwrap_or(N);
+  // the library writes only ASCII characters
+  unsafe { core::str::from_utf8_unchecked(&buf[..len]) }
+}
+
+/// Writes 128-bit decimal floating-point value (binary encoding)
+/// in string format (decimal character sequence) without allocating memory.
+pub fn bid128_write_to(x: BID128, w: &mut impl fmt::Write, flags: &mut u32) -> fmt::Result {
+  let mut buf = [0_u8; BID128_STRING_LENGTH];
+  w.write_str(bid128_to_str_buf(x, &mut buf, flags))
+}
+
+/// Converts the initial part of the string to 128-bit decimal floating-point number,
+/// skipping leading whitespace. Returns the converted value and the number of consumed bytes,
+/// the number of consumed bytes is zero when no conversion could be performed.
+/// The value is always rounded to nearest even and exception flags are not reported.
+/// Only the leading run of characters that may form a number is passed to the Intel library,
+/// so the cost of the conversion depends on the length of the number, not on the length of the string.
+#[cfg(feature = "std")]
+pub fn bid_strtod128(s: &str) -> (BID128, usize) {
+  // whitespace recognized by `isspace` in the C locale
+  let skipped = s.bytes().take_while(|b| matches!(b, b' ' | b'\t' | b'\n' | b'\x0B' | b'\x0C' | b'\r')).count();
+  let candidate = &s.as_bytes()[skipped..];
+  let length = candidate.iter().take_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.')).count();
+  let candidate = &candidate[..length];
+  let (x, consumed) = if length < FROM_BYTES_BUFFER_LENGTH {
+    let mut buf = [0_u8; FROM_BYTES_BUFFER_LENGTH];
+    buf[..length].copy_from_slice(candidate);
+    strtod128(buf.as_ptr() as *const c_char)
+  } else {
+    // the candidate contains no NUL characters
+    let c_s = CString::new(candidate).unwrap();
+    strtod128(c_s.as_ptr())
+  };
+  if consumed == 0 {
+    (x, 0)
+  } else {
+    (x, skipped + consumed)
+  }
+}
+
+/// Calls `bid_strtod128` and returns the converted value and the number of consumed bytes.
+#[cfg(feature = "std")]
+fn strtod128(s: *const c_char) -> (BID128, usize) {
+  let mut end: *mut c_char = core::ptr::null_mut();
+  let x = unsafe { __bid_strtod128(s, &mut end) };
+  (x, if end.is_null() { 0 } else { end as usize - s as usize })
 }
 
 /*
@@ -565,3 +697,108 @@ __bid128qqd_fma
 
 
 */
+
+/// Results of exact additions, subtractions and multiplications are calculated without calling
+/// the Intel library, so they are compared bit by bit with results calculated by the Intel library.
+#[cfg(all(test, feature = "std"))]
+mod tests {
+  use super::*;
+  use crate::bid128::bid128_from_words;
+  use crate::{FB_CLEAR, RM_DOWNWARD, RM_NEAREST_AWAY, RM_NEAREST_EVEN, RM_TOWARD_ZERO, RM_UPWARD};
+
+  const ROUNDING: [u32; 5] = [RM_NEAREST_EVEN, RM_DOWNWARD, RM_UPWARD, RM_TOWARD_ZERO, RM_NEAREST_AWAY];
+
+  /// Simple xorshift generator, so the tests are repeatable.
+  struct Random(u64);
+
+  impl Random {
+    fn next(&mut self) -> u64 {
+      self.0 ^= self.0 << 13;
+      self.0 ^= self.0 >> 7;
+      self.0 ^= self.0 << 17;
+      self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+      self.next() % n
+    }
+
+    /// Returns a decimal with random sign, number of digits and exponent,
+    /// exponents are mostly close to zero to make exact operations likely.
+    fn decimal(&mut self) -> BID128 {
+      let mut coefficient = 0_u128;
+      for _ in 0..self.below(35) {
+        coefficient = coefficient * 10 + self.below(10) as u128;
+      }
+      let exponent = match self.below(8) {
+        0 => self.below(12288) as i64 - 6176,
+        1 => -6176 + self.below(40) as i64,
+        2 => 6111 - self.below(40) as i64,
+        _ => self.below(20) as i64 - 10,
+      };
+      let sign = self.below(2) << 63;
+      let x = bid128_from_string(&format!("{}E{}", coefficient, exponent), RM_NEAREST_EVEN, &mut FB_CLEAR.clone());
+      let x = if sign != 0 { bid128_negate(x) } else { x };
+      match self.below(40) {
+        0 => bid128_from_string("NaN", RM_NEAREST_EVEN, &mut FB_CLEAR.clone()),
+        1 => bid128_inf(),
+        2 => bid128_negate(bid128_inf()),
+        // non-canonical coefficient
+        3 => bid128_from_words(0x3041_FFFF_FFFF_FFFF | sign, u64::MAX),
+        // non-canonical coefficient with steering bits `11`
+        4 => bid128_from_words(0x6000_0000_0000_0000 | sign, 0),
+        _ => x,
+      }
+    }
+  }
+
+  fn assert_same(expected: (BID128, u32), actual: (BID128, u32), context: &str) {
+    assert_eq!(expected.0.w, actual.0.w, "{context}");
+    assert_eq!(expected.1, actual.1, "{context}");
+  }
+
+  #[test]
+  fn test_bid128_add_exact() {
+    let mut random = Random(0x2545_F491_4F6C_DD1D);
+    for _ in 0..100_000 {
+      let (x, y) = (random.decimal(), random.decimal());
+      for round in ROUNDING {
+        let mut flags = FB_CLEAR;
+        let actual = bid128_add(x, y, round, &mut flags);
+        let mut expected_flags = FB_CLEAR;
+        let expected = unsafe { __bid128_add(x, y, round, &mut expected_flags) };
+        assert_same((expected, expected_flags), (actual, flags), &format!("{:x?} + {:x?}, round = {round}", x.w, y.w));
+      }
+    }
+  }
+
+  #[test]
+  fn test_bid128_sub_exact() {
+    let mut random = Random(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..100_000 {
+      let (x, y) = (random.decimal(), random.decimal());
+      for round in ROUNDING {
+        let mut flags = FB_CLEAR;
+        let actual = bid128_sub(x, y, round, &mut flags);
+        let mut expected_flags = FB_CLEAR;
+        let expected = unsafe { __bid128_sub(x, y, round, &mut expected_flags) };
+        assert_same((expected, expected_flags), (actual, flags), &format!("{:x?} - {:x?}, round = {round}", x.w, y.w));
+      }
+    }
+  }
+
+  #[test]
+  fn test_bid128_mul_exact() {
+    let mut random = Random(0xD1B5_4A32_D192_ED03);
+    for _ in 0..100_000 {
+      let (x, y) = (random.decimal(), random.decimal());
+      for round in ROUNDING {
+        let mut flags = FB_CLEAR;
+        let actual = bid128_mul(x, y, round, &mut flags);
+        let mut expected_flags = FB_CLEAR;
+        let expected = unsafe { __bid128_mul(x, y, round, &mut expected_flags) };
+        assert_same((expected, expected_flags), (actual, flags), &format!("{:x?} * {:x?}, round = {round}", x.w, y.w));
+      }
+    }
+  }
+}
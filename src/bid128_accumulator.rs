@@ -0,0 +1,225 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Exact summation of 128-bit decimals.
+//!
+//! Coefficients of added values are kept as wide integers per exponent,
+//! so the sum is rounded only once, when the accumulation is finished,
+//! and the result does not depend on the order in which values were added.
+
+use crate::bid128::{decode, digits, encode, round_digits, Decoded, HIGH, MAX_COEFFICIENT, MAX_EXPONENT};
+use crate::{bid128_add, BID128, FB_INEXACT, FB_OVERFLOW, RM_DOWNWARD, RM_NEAREST_AWAY, RM_NEAREST_EVEN, RM_UPWARD};
+use std::collections::BTreeMap;
+
+/// Limit of the sum kept in a single bucket, larger sums are carried to the bucket with the next exponent.
+const BUCKET_LIMIT: i128 = 10_i128.pow(37);
+/// Base of limbs used when the sum of all buckets is calculated.
+const LIMB_BASE: i128 = 10_i128.pow(LIMB_DIGITS as u32);
+/// Number of decimal digits in a single limb.
+const LIMB_DIGITS: usize = 18;
+
+/// Accumulator calculating the exact sum of 128-bit decimals, rounded once when finished.
+#[derive(Debug, Clone, Default)]
+pub struct ExactAccumulator {
+  /// Sums of coefficients of added values, by exponent.
+  buckets: BTreeMap<i32, i128>,
+  /// Minimum exponent of added finite values, this is the preferred exponent of the sum.
+  min_exponent: Option<i32>,
+  /// Sum of added infinities and NaNs.
+  special: Option<BID128>,
+  /// Exception flags raised while adding infinities and NaNs.
+  flags: u32,
+  /// Set when a finite value with positive sign was added.
+  positive: bool,
+  /// Set when a finite value with negative sign was added.
+  negative: bool,
+}
+
+impl ExactAccumulator {
+  /// Creates an empty accumulator.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds the value to the sum, no rounding takes place.
+  pub fn add(&mut self, x: BID128) {
+    match decode(x) {
+      Decoded::Finite(sign, coefficient, exponent) => {
+        self.min_exponent = Some(self.min_exponent.map_or(exponent, |min| min.min(exponent)));
+        if sign {
+          self.negative = true;
+          self.add_coefficient(exponent, -(coefficient as i128));
+        } else {
+          self.positive = true;
+          self.add_coefficient(exponent, coefficient as i128);
+        }
+      }
+      _ => {
+        // infinities and NaNs are added by the Intel library, so the result and flags are the same as for addition
+        let zero = encode(false, 0, 0);
+        self.special = Some(bid128_add(self.special.unwrap_or(zero), x, RM_NEAREST_EVEN, &mut self.flags));
+      }
+    }
+  }
+
+  /// Adds all values accumulated in another accumulator.
+  pub fn merge(&mut self, other: &ExactAccumulator) {
+    for (exponent, sum) in &other.buckets {
+      self.add_coefficient(*exponent, *sum);
+    }
+    if let Some(exponent) = other.min_exponent {
+      self.min_exponent = Some(self.min_exponent.map_or(exponent, |min| min.min(exponent)));
+    }
+    if let Some(special) = other.special {
+      self.add(special);
+    }
+    self.flags |= other.flags;
+    self.positive |= other.positive;
+    self.negative |= other.negative;
+  }
+
+  /// Returns the sum of all added values, rounded according to the specified rounding mode.
+  /// [FB_INEXACT] is raised only when the rounding loses nonzero digits.
+  /// The sum of no values is `+0E+0`.
+  pub fn finish(&self, round: u32, flags: &mut u32) -> BID128 {
+    if let Some(special) = self.special {
+      *flags |= self.flags;
+      return special;
+    }
+    let Some(min_exponent) = self.min_exponent else {
+      return encode(false, 0, 0);
+    };
+    let (negative, limbs) = self.magnitude(min_exponent);
+    let Some(top) = limbs.iter().rposition(|limb| *limb != 0) else {
+      // exact zero sum is negative when all values were negative, or when rounding downward values with opposite signs
+      let negative = self.negative && (!self.positive || round == RM_DOWNWARD);
+      return encode(negative, 0, min_exponent);
+    };
+    let count = top * LIMB_DIGITS + digits(limbs[top] as u128) as usize;
+    if count <= 34 {
+      let coefficient = limbs.iter().take(2).rev().fold(0, |coefficient, limb| coefficient * LIMB_BASE as u128 + *limb as u128);
+      return encode(negative, coefficient, min_exponent);
+    }
+    // leading 35 digits are followed by a sticky digit, set when any of the remaining digits is nonzero
+    let dropped = count - 35;
+    let leading = (dropped..count).rev().fold(0, |leading, position| leading * 10 + digit(&limbs, position) as u128);
+    let sticky = limbs[..dropped / LIMB_DIGITS].iter().any(|limb| *limb != 0) || limbs[dropped / LIMB_DIGITS] % 10_i128.pow((dropped % LIMB_DIGITS) as u32) != 0;
+    let rounded = leading * 10 + sticky as u128;
+    let mut coefficient = round_digits(rounded, 2, negative, round);
+    let mut exponent = min_exponent + dropped as i32 + 1;
+    if coefficient > MAX_COEFFICIENT {
+      coefficient /= 10;
+      exponent += 1;
+    }
+    if !rounded.is_multiple_of(100) {
+      *flags |= FB_INEXACT;
+    }
+    if exponent > MAX_EXPONENT {
+      *flags |= FB_OVERFLOW | FB_INEXACT;
+      let infinite = match round {
+        RM_NEAREST_EVEN | RM_NEAREST_AWAY => true,
+        RM_DOWNWARD => negative,
+        RM_UPWARD => !negative,
+        _ => false,
+      };
+      return if infinite {
+        let mut x = encode(negative, 0, 0);
+        x.w[HIGH] = (negative as u64) << 63 | 0x7800_0000_0000_0000;
+        x
+      } else {
+        encode(negative, MAX_COEFFICIENT, MAX_EXPONENT)
+      };
+    }
+    encode(negative, coefficient, exponent)
+  }
+
+  /// Adds the signed coefficient to the bucket, carrying excess digits to buckets with higher exponents.
+  fn add_coefficient(&mut self, mut exponent: i32, mut coefficient: i128) {
+    while coefficient != 0 {
+      let sum = self.buckets.entry(exponent).or_insert(0);
+      *sum += coefficient;
+      if sum.abs() < BUCKET_LIMIT {
+        break;
+      }
+      coefficient = *sum / 10;
+      *sum %= 10;
+      exponent += 1;
+    }
+  }
+
+  /// Returns the sign and the magnitude of the sum of all buckets,
+  /// as little-endian limbs of the coefficient with the specified exponent.
+  fn magnitude(&self, min_exponent: i32) -> (bool, Vec<i128>) {
+    let max_exponent = self.buckets.keys().next_back().copied().unwrap_or(min_exponent);
+    let mut limbs = vec![0_i128; (max_exponent - min_exponent) as usize / LIMB_DIGITS + 5];
+    for (exponent, sum) in &self.buckets {
+      let offset = (exponent - min_exponent) as usize;
+      let scale = 10_i128.pow((offset % LIMB_DIGITS) as u32);
+      // the sum has at most 37 digits, so it is split into three limbs before shifting
+      let parts = [sum % LIMB_BASE, sum / LIMB_BASE % LIMB_BASE, sum / LIMB_BASE / LIMB_BASE];
+      for (i, part) in parts.iter().enumerate() {
+        limbs[offset / LIMB_DIGITS + i] += part * scale;
+      }
+    }
+    let carry = normalize(&mut limbs);
+    if carry < 0 {
+      limbs.iter_mut().for_each(|limb| *limb = -*limb);
+      normalize(&mut limbs);
+      (true, limbs)
+    } else {
+      (false, limbs)
+    }
+  }
+}
+
+impl Extend<BID128> for ExactAccumulator {
+  fn extend<T: IntoIterator<Item = BID128>>(&mut self, iter: T) {
+    iter.into_iter().for_each(|x| self.add(x));
+  }
+}
+
+impl FromIterator<BID128> for ExactAccumulator {
+  fn from_iter<T: IntoIterator<Item = BID128>>(iter: T) -> Self {
+    let mut accumulator = Self::new();
+    accumulator.extend(iter);
+    accumulator
+  }
+}
+
+/// Normalizes limbs to the range `0..LIMB_BASE` and returns the final carry,
+/// which is negative when the value represented by limbs is negative.
+fn normalize(limbs: &mut [i128]) -> i128 {
+  let mut carry = 0;
+  for limb in limbs.iter_mut() {
+    let value = *limb + carry;
+    *limb = value.rem_euclid(LIMB_BASE);
+    carry = value.div_euclid(LIMB_BASE);
+  }
+  carry
+}
+
+/// Returns the decimal digit at the specified position of the value represented by normalized limbs.
+fn digit(limbs: &[i128], position: usize) -> i128 {
+  limbs[position / LIMB_DIGITS] / 10_i128.pow((position % LIMB_DIGITS) as u32) % 10
+}
@@ -0,0 +1,367 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Formatting of 128-bit decimals.
+//!
+//! Values are formatted directly from the coefficient and exponent,
+//! so no precision is lost and no conversion through binary floating-point is made.
+
+use crate::bid128::{decode, digits, round_digits, Decoded};
+use crate::{BID128, RM_NEAREST_EVEN};
+use std::fmt;
+
+/// Notation used when formatting decimals.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Notation {
+  /// Fixed-point notation, like `1234.5`.
+  Fixed,
+  /// Scientific notation, like `1.2345e3`.
+  Scientific,
+  /// Engineering notation, the exponent is a multiple of three, like `12.345e-6`.
+  Engineering,
+}
+
+/// Options for formatting decimals.
+#[derive(Debug, Copy, Clone)]
+pub struct FormatOptions {
+  /// Notation of the formatted value.
+  pub notation: Notation,
+  /// Number of digits after the decimal point, `None` means all significant digits.
+  pub precision: Option<usize>,
+  /// Rounding mode used when digits are removed to fit the precision.
+  pub round: u32,
+  /// When `true`, trailing zeros from the quantum are preserved, e.g. `1.2300` instead of `1.23`.
+  pub trailing_zeros: bool,
+}
+
+impl Default for FormatOptions {
+  /// Returns fixed-point notation with all significant digits, rounding to nearest even.
+  fn default() -> Self {
+    Self {
+      notation: Notation::Fixed,
+      precision: None,
+      round: RM_NEAREST_EVEN,
+      trailing_zeros: false,
+    }
+  }
+}
+
+/// 128-bit decimal with formatting options, ready to be displayed.
+///
+/// Width, fill, alignment, sign and precision given in format specifiers are honored,
+/// the precision overrides the one set in [FormatOptions].
+#[derive(Debug, Copy, Clone)]
+pub struct Formatted {
+  x: BID128,
+  options: FormatOptions,
+}
+
+impl fmt::Display for Formatted {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write_formatted(f, self.x, &self.options, 'e')
+  }
+}
+
+/// Formats 128-bit decimal in fixed-point notation,
+/// e.g. `format!("{:+>10.2}", x)` rounds the value to two places.
+impl fmt::Display for BID128 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write_formatted(f, *self, &FormatOptions::default(), 'e')
+  }
+}
+
+/// Formats 128-bit decimal in scientific notation with lowercase exponent marker.
+impl fmt::LowerExp for BID128 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let options = FormatOptions {
+      notation: Notation::Scientific,
+      ..Default::default()
+    };
+    write_formatted(f, *self, &options, 'e')
+  }
+}
+
+/// Formats 128-bit decimal in scientific notation with uppercase exponent marker.
+impl fmt::UpperExp for BID128 {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let options = FormatOptions {
+      notation: Notation::Scientific,
+      ..Default::default()
+    };
+    write_formatted(f, *self, &options, 'E')
+  }
+}
+
+/// Returns 128-bit decimal prepared for formatting with specified options.
+///
+/// The result implements [fmt::Display], so it can be converted to [String]
+/// or used with any format specifiers, like `format!("{:>12}", bid128_format(x, options))`.
+pub fn bid128_format(x: BID128, options: FormatOptions) -> Formatted {
+  Formatted { x, options }
+}
+
+/// Style of formatting negative values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NegativeStyle {
+  /// Leading minus sign, like `-1,234.5`.
+  Minus,
+  /// Value enclosed in parentheses, like `(1,234.5)`.
+  Parentheses,
+  /// Trailing minus sign, like `1,234.5-`.
+  TrailingMinus,
+}
+
+/// Locale-specific conventions for formatting decimals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+  /// Character separating the integral and fractional part.
+  pub decimal_mark: char,
+  /// Character separating the groups of digits in the integral part.
+  pub grouping_separator: char,
+  /// Sizes of the digit groups, starting from the decimal mark; the last size is repeated.
+  /// For example `[3]` gives `1,234,567` and `[3, 2]` gives `12,34,567` (lakh/crore).
+  /// Empty grouping or group size zero disables further grouping.
+  pub grouping: Vec<usize>,
+  /// Style of formatting negative values.
+  pub negative: NegativeStyle,
+}
+
+impl Default for Locale {
+  /// Returns locale with decimal point, no grouping and leading minus sign.
+  fn default() -> Self {
+    Self {
+      decimal_mark: '.',
+      grouping_separator: ',',
+      grouping: vec![],
+      negative: NegativeStyle::Minus,
+    }
+  }
+}
+
+impl Locale {
+  /// Returns locale with specified decimal mark, grouping separator and digit groups.
+  pub fn new(decimal_mark: char, grouping_separator: char, grouping: &[usize], negative: NegativeStyle) -> Self {
+    Self {
+      decimal_mark,
+      grouping_separator,
+      grouping: grouping.to_vec(),
+      negative,
+    }
+  }
+}
+
+/// Formats 128-bit decimal using specified options and locale conventions.
+///
+/// Digits of the integral part are grouped only in fixed-point notation,
+/// the decimal mark is applied in every notation.
+pub fn bid128_format_locale(x: BID128, options: FormatOptions, locale: &Locale) -> String {
+  let (negative, body) = match decode(x) {
+    Decoded::NaN(_) => return "NaN".to_string(),
+    Decoded::Infinite(negative) => (negative, "inf".to_string()),
+    Decoded::Finite(negative, coefficient, exponent) => {
+      let rendered = render(negative, coefficient, exponent, &options, 'e');
+      let integral = rendered.find(|ch: char| !ch.is_ascii_digit()).unwrap_or(rendered.len());
+      let mut body = if options.notation == Notation::Fixed {
+        group_digits(&rendered[..integral], locale.grouping_separator, &locale.grouping)
+      } else {
+        rendered[..integral].to_string()
+      };
+      let rest = &rendered[integral..];
+      match rest.strip_prefix('.') {
+        Some(fraction) => {
+          body.push(locale.decimal_mark);
+          body.push_str(fraction);
+        }
+        None => body.push_str(rest),
+      }
+      (negative, body)
+    }
+  };
+  match (negative, locale.negative) {
+    (false, _) => body,
+    (true, NegativeStyle::Minus) => format!("-{body}"),
+    (true, NegativeStyle::Parentheses) => format!("({body})"),
+    (true, NegativeStyle::TrailingMinus) => format!("{body}-"),
+  }
+}
+
+/// Inserts the separator between the groups of digits.
+fn group_digits(digits: &str, separator: char, grouping: &[usize]) -> String {
+  let mut groups = vec![];
+  let mut end = digits.len();
+  let mut index = 0;
+  while end > 0 {
+    let size = grouping.get(index).or(grouping.last()).copied().unwrap_or(0);
+    if size == 0 || size >= end {
+      groups.push(&digits[..end]);
+      break;
+    }
+    groups.push(&digits[end - size..end]);
+    end -= size;
+    index += 1;
+  }
+  groups.reverse();
+  groups.join(&separator.to_string())
+}
+
+/// Writes the formatted value, applying padding and sign from formatter.
+fn write_formatted(f: &mut fmt::Formatter<'_>, x: BID128, options: &FormatOptions, marker: char) -> fmt::Result {
+  match decode(x) {
+    Decoded::NaN(_) => f.pad("NaN"),
+    Decoded::Infinite(negative) => f.pad_integral(!negative, "", "inf"),
+    Decoded::Finite(negative, coefficient, exponent) => {
+      let options = FormatOptions {
+        precision: f.precision().or(options.precision),
+        ..*options
+      };
+      f.pad_integral(!negative, "", &render(negative, coefficient, exponent, &options, marker))
+    }
+  }
+}
+
+/// Renders the absolute value of finite decimal.
+fn render(negative: bool, coefficient: u128, exponent: i32, options: &FormatOptions, marker: char) -> String {
+  let (coefficient, exponent) = if options.trailing_zeros {
+    (coefficient, exponent as i64)
+  } else {
+    strip_trailing_zeros(coefficient, exponent as i64)
+  };
+  match options.notation {
+    Notation::Fixed => render_fixed(negative, coefficient, exponent, options),
+    Notation::Scientific => render_scientific(negative, coefficient, exponent, options, marker),
+    Notation::Engineering => render_engineering(negative, coefficient, exponent, options, marker),
+  }
+}
+
+/// Renders the value in fixed-point notation.
+fn render_fixed(negative: bool, mut coefficient: u128, mut exponent: i64, options: &FormatOptions) -> String {
+  if let Some(precision) = options.precision {
+    let target = -(precision.min(i32::MAX as usize) as i64);
+    if exponent < target {
+      coefficient = round_digits(coefficient, (target - exponent) as u32, negative, options.round);
+      exponent = target;
+    }
+  }
+  let mut s = coefficient.to_string();
+  let mut fraction = 0;
+  if exponent >= 0 {
+    if coefficient != 0 {
+      s.push_str(&"0".repeat(exponent as usize));
+    }
+  } else {
+    fraction = (-exponent) as usize;
+    if s.len() <= fraction {
+      s.insert_str(0, &"0".repeat(fraction - s.len() + 1));
+    }
+    s.insert(s.len() - fraction, '.');
+  }
+  if let Some(precision) = options.precision {
+    if precision > fraction {
+      if fraction == 0 {
+        s.push('.');
+      }
+      s.push_str(&"0".repeat(precision - fraction));
+    }
+  }
+  s
+}
+
+/// Renders the value in scientific notation.
+fn render_scientific(negative: bool, mut coefficient: u128, mut exponent: i64, options: &FormatOptions, marker: char) -> String {
+  if let Some(precision) = options.precision {
+    (coefficient, exponent) = round_significant(coefficient, exponent, precision.saturating_add(1), negative, options.round);
+  }
+  let mut s = coefficient.to_string();
+  let adjusted = exponent + s.len() as i64 - 1;
+  if let Some(precision) = options.precision {
+    s.push_str(&"0".repeat((precision + 1).saturating_sub(s.len())));
+  }
+  if s.len() > 1 {
+    s.insert(1, '.');
+  }
+  format!("{s}{marker}{adjusted}")
+}
+
+/// Renders the value in engineering notation.
+fn render_engineering(negative: bool, mut coefficient: u128, mut exponent: i64, options: &FormatOptions, marker: char) -> String {
+  if coefficient == 0 {
+    // zero keeps its exponent rounded up to the multiple of three, like 0E-5 -> 0.00e-3
+    let engineering = exponent + (3 - exponent.rem_euclid(3)) % 3;
+    let fraction = options.precision.unwrap_or((engineering - exponent) as usize);
+    return if fraction > 0 {
+      format!("0.{}{marker}{engineering}", "0".repeat(fraction))
+    } else {
+      format!("0{marker}{engineering}")
+    };
+  }
+  if let Some(precision) = options.precision {
+    let adjusted = exponent + digits(coefficient) as i64 - 1;
+    let integral = adjusted.rem_euclid(3) as usize + 1;
+    (coefficient, exponent) = round_significant(coefficient, exponent, precision.saturating_add(integral), negative, options.round);
+  }
+  let mut s = coefficient.to_string();
+  let adjusted = exponent + s.len() as i64 - 1;
+  let engineering = adjusted - adjusted.rem_euclid(3);
+  let integral = (adjusted - engineering) as usize + 1;
+  if s.len() < integral {
+    s.push_str(&"0".repeat(integral - s.len()));
+  }
+  if let Some(precision) = options.precision {
+    // rounding may carry into the next multiple of three, only zeros are truncated then
+    s.truncate(integral + precision);
+    s.push_str(&"0".repeat((integral + precision).saturating_sub(s.len())));
+  }
+  if s.len() > integral {
+    s.insert(integral, '.');
+  }
+  format!("{s}{marker}{engineering}")
+}
+
+/// Removes trailing zeros from the coefficient, zero gets exponent equal to zero.
+fn strip_trailing_zeros(mut coefficient: u128, mut exponent: i64) -> (u128, i64) {
+  if coefficient == 0 {
+    return (0, 0);
+  }
+  while coefficient.is_multiple_of(10) {
+    coefficient /= 10;
+    exponent += 1;
+  }
+  (coefficient, exponent)
+}
+
+/// Rounds the coefficient to the specified number of significant digits.
+fn round_significant(coefficient: u128, exponent: i64, significant: usize, negative: bool, round: u32) -> (u128, i64) {
+  let count = digits(coefficient) as usize;
+  if count <= significant {
+    return (coefficient, exponent);
+  }
+  let removed = (count - significant) as u32;
+  let rounded = round_digits(coefficient, removed, negative, round);
+  if digits(rounded) as usize > significant {
+    // carry produced the power of ten with one digit too many
+    (rounded / 10, exponent + removed as i64 + 1)
+  } else {
+    (rounded, exponent + removed as i64)
+  }
+}
@@ -0,0 +1,265 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Parsing of 128-bit decimals with detailed errors.
+
+use crate::bid128_000::bid128_from_c_str;
+use crate::{bid_strtod128, BID128, FB_INEXACT, FB_OVERFLOW};
+use std::ffi::CString;
+use std::fmt;
+
+/// Maximum absolute value of the exponent accepted by the Intel library.
+const MAX_EXPONENT: i64 = 9_999_999;
+
+/// Mode of parsing decimals.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseMode {
+  /// Only the exact decimal syntax is accepted and the value must be representable without rounding.
+  Strict,
+  /// Leading and trailing whitespace, underscores between digits and leading `+` are accepted,
+  /// the value is rounded when it has too many digits.
+  Lenient,
+}
+
+/// Errors reported while parsing decimals.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseError {
+  /// The input is empty or contains only whitespace.
+  Empty,
+  /// Unexpected character at specified byte position.
+  InvalidChar(usize, char),
+  /// Digits are missing at specified byte position, like in `1e` or `-`.
+  MissingDigits(usize),
+  /// The exponent is out of range, or the value is too large to be represented.
+  ExponentOverflow,
+  /// The value has too many digits and was rounded (reported only in strict mode).
+  Inexact,
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ParseError::Empty => write!(f, "empty input"),
+      ParseError::InvalidChar(position, ch) => write!(f, "invalid character {ch:?} at position {position}"),
+      ParseError::MissingDigits(position) => write!(f, "missing digits at position {position}"),
+      ParseError::ExponentOverflow => write!(f, "exponent out of range"),
+      ParseError::Inexact => write!(f, "value can not be represented exactly"),
+    }
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a decimal floating-point value represented in string format (decimal character sequence)
+/// to 128-bit decimal floating-point format (binary encoding).
+///
+/// Unlike [bid128_from_string], malformed input is reported as an error instead of being converted to NaN.
+/// Accepted special values are `NaN`, `sNaN`, `Inf` and `Infinity` (case-insensitive, optionally signed).
+pub fn bid128_parse(s: &str, mode: ParseMode, round: u32, flags: &mut u32) -> Result<BID128, ParseError> {
+  // the normalized number contains no NUL characters
+  let normalized = CString::new(normalize(s, mode)?).unwrap();
+  let mut status = *flags;
+  let x = bid128_from_c_str(&normalized, round, &mut status);
+  if status & FB_OVERFLOW != 0 {
+    return Err(ParseError::ExponentOverflow);
+  }
+  if mode == ParseMode::Strict && status & FB_INEXACT != 0 {
+    return Err(ParseError::Inexact);
+  }
+  *flags = status;
+  Ok(x)
+}
+
+/// Parses the decimal number at the beginning of the string, skipping leading whitespace.
+///
+/// Returns the parsed value and the number of consumed bytes, or `None` when the string
+/// does not start with a number. The string is inspected only up to the end of the leading run
+/// of characters that may form a number, so this function can be called at every position
+/// while splitting the input into tokens, without scanning the rest of the input each time.
+pub fn bid128_parse_prefix(s: &str) -> Option<(BID128, usize)> {
+  match bid_strtod128(s) {
+    (_, 0) => None,
+    (x, consumed) => Some((x, consumed)),
+  }
+}
+
+/// Parses the decimal number at the beginning of the UTF-16 encoded buffer, like `wchar_t` strings on Windows.
+///
+/// Returns the parsed value and the number of consumed code units, see [bid128_parse_prefix] for details.
+pub fn bid128_parse_prefix_utf16(s: &[u16]) -> Option<(BID128, usize)> {
+  parse_prefix_wide(s.iter().map(|unit| *unit as u32))
+}
+
+/// Parses the decimal number at the beginning of the UTF-32 encoded buffer, like `wchar_t` strings on Linux and macOS.
+///
+/// Returns the parsed value and the number of consumed code units, see [bid128_parse_prefix] for details.
+pub fn bid128_parse_prefix_utf32(s: &[u32]) -> Option<(BID128, usize)> {
+  parse_prefix_wide(s.iter().copied())
+}
+
+/// Parses the number from wide characters.
+///
+/// The `bid_wcstod128` function from the Intel library writes past the end of its internal buffer,
+/// so the wide characters are narrowed here and parsed by `bid_strtod128` instead.
+/// Only the leading run of characters that may form a number is narrowed,
+/// and only the whitespace recognized by `iswspace` in the C locale is skipped.
+fn parse_prefix_wide(units: impl Iterator<Item = u32>) -> Option<(BID128, usize)> {
+  let mut units = units.peekable();
+  let mut skipped = 0;
+  while units.next_if(|unit| matches!(unit, 0x20 | 0x09..=0x0D)).is_some() {
+    skipped += 1;
+  }
+  let narrow: String = units
+    .map_while(|unit| char::from_u32(unit).filter(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.')))
+    .collect();
+  bid128_parse_prefix(&narrow).map(|(x, consumed)| (x, skipped + consumed))
+}
+
+/// Validates the input and returns the number in the form accepted by the Intel library.
+fn normalize(s: &str, mode: ParseMode) -> Result<String, ParseError> {
+  let lenient = mode == ParseMode::Lenient;
+  let (start, input) = if lenient {
+    let trimmed = s.trim_start();
+    (s.len() - trimmed.len(), trimmed.trim_end())
+  } else {
+    (0, s)
+  };
+  if input.is_empty() {
+    return Err(ParseError::Empty);
+  }
+  let mut chars = input.char_indices().map(|(i, ch)| (start + i, ch)).peekable();
+  let mut normalized = String::with_capacity(input.len());
+  match chars.peek() {
+    Some(&(_, '-')) => {
+      normalized.push('-');
+      chars.next();
+    }
+    Some(&(_, '+')) if lenient => {
+      chars.next();
+    }
+    _ => {}
+  }
+  // special values
+  if let Some(&(position, ch)) = chars.peek() {
+    if ch.is_ascii_alphabetic() {
+      let word: String = chars.map(|(_, ch)| ch).collect();
+      let lowercase = word.to_ascii_lowercase();
+      for special in ["nan", "snan", "inf", "infinity"] {
+        if lowercase == special {
+          normalized.push_str(special);
+          return Ok(normalized);
+        }
+      }
+      // report the first character that does not continue any special value
+      let mut matched = 0;
+      for (i, ch) in lowercase.char_indices() {
+        let prefix = &lowercase[..i + ch.len_utf8()];
+        if !["nan", "snan", "infinity"].iter().any(|special| special.starts_with(prefix)) {
+          return Err(ParseError::InvalidChar(position + i, word[i..].chars().next().unwrap()));
+        }
+        matched = i + ch.len_utf8();
+      }
+      return Err(ParseError::MissingDigits(position + matched));
+    }
+  }
+  // coefficient
+  let mut digit_count = 0;
+  let mut point = false;
+  let mut after_digit = false;
+  // position of the underscore that must be followed by a digit
+  let mut underscore = None;
+  while let Some(&(position, ch)) = chars.peek() {
+    separated(&mut underscore, ch)?;
+    match ch {
+      '0'..='9' => {
+        normalized.push(ch);
+        digit_count += 1;
+        after_digit = true;
+      }
+      '.' if !point => {
+        normalized.push(ch);
+        point = true;
+        after_digit = false;
+      }
+      '_' if lenient && after_digit => underscore = Some(position),
+      'e' | 'E' => break,
+      _ => return Err(ParseError::InvalidChar(position, ch)),
+    }
+    chars.next();
+  }
+  let end = start + input.len();
+  separated(&mut underscore, '\0')?;
+  if digit_count == 0 {
+    return Err(ParseError::MissingDigits(chars.peek().map_or(end, |&(position, _)| position)));
+  }
+  // exponent
+  if chars.next().is_some() {
+    let mut negative = false;
+    match chars.peek() {
+      Some(&(_, '-')) => {
+        negative = true;
+        chars.next();
+      }
+      Some(&(_, '+')) => {
+        chars.next();
+      }
+      _ => {}
+    }
+    let mut exponent: i64 = 0;
+    let mut exponent_digits = 0;
+    for (position, ch) in chars {
+      separated(&mut underscore, ch)?;
+      match ch {
+        '0'..='9' => {
+          exponent = exponent.saturating_mul(10).saturating_add((ch as u8 - b'0') as i64);
+          exponent_digits += 1;
+        }
+        '_' if lenient && exponent_digits > 0 => underscore = Some(position),
+        _ => return Err(ParseError::InvalidChar(position, ch)),
+      }
+    }
+    separated(&mut underscore, '\0')?;
+    if exponent_digits == 0 {
+      return Err(ParseError::MissingDigits(end));
+    }
+    if exponent > MAX_EXPONENT {
+      return Err(ParseError::ExponentOverflow);
+    }
+    normalized.push('E');
+    if negative {
+      normalized.push('-');
+    }
+    normalized.push_str(&exponent.to_string());
+  }
+  Ok(normalized)
+}
+
+/// Checks that the pending underscore is followed by a digit, so underscores
+/// are accepted only between digits, e.g. `1_000`, but not in `1_` or `1__000`.
+fn separated(underscore: &mut Option<usize>, next: char) -> Result<(), ParseError> {
+  match underscore.take() {
+    Some(position) if !next.is_ascii_digit() => Err(ParseError::InvalidChar(position, '_')),
+    _ => Ok(()),
+  }
+}
@@ -0,0 +1,110 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Decomposition of 128-bit decimals into sign, coefficient and exponent.
+
+use crate::bid128::{decode, encode, Decoded, HIGH, LOW, MAX_COEFFICIENT, MAX_EXPONENT, MIN_EXPONENT};
+use crate::BID128;
+use core::fmt;
+
+/// Maximum payload of canonical NaN, `10^33 - 1`.
+const MAX_PAYLOAD: u128 = 999_999_999_999_999_999_999_999_999_999_999;
+
+/// Parts of 128-bit decimal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Parts {
+  /// Finite value: sign (`true` when negative), coefficient and exponent,
+  /// the value is equal to `coefficient * 10^exponent`.
+  Finite(bool, u128, i32),
+  /// Infinity with sign.
+  Infinite(bool),
+  /// NaN: sign, `true` when signaling, and payload.
+  NaN(bool, bool, u128),
+}
+
+/// Errors reported while composing decimals from parts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PartsError {
+  /// The coefficient is greater than `10^34 - 1`, or the payload of NaN is greater than `10^33 - 1`.
+  CoefficientOutOfRange,
+  /// The exponent is out of range `-6176..=6111`.
+  ExponentOutOfRange,
+}
+
+impl fmt::Display for PartsError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      PartsError::CoefficientOutOfRange => write!(f, "coefficient out of range"),
+      PartsError::ExponentOutOfRange => write!(f, "exponent out of range"),
+    }
+  }
+}
+
+impl core::error::Error for PartsError {}
+
+/// Returns the sign, coefficient and exponent of 128-bit decimal.
+/// Non-canonical coefficients and payloads are returned as zero, like in the Intel library.
+pub fn bid128_to_parts(x: BID128) -> Parts {
+  match decode(x) {
+    Decoded::Finite(sign, coefficient, exponent) => Parts::Finite(sign, coefficient, exponent),
+    Decoded::Infinite(sign) => Parts::Infinite(sign),
+    Decoded::NaN(sign) => {
+      let signaling = x.w[HIGH] & 0x7E00_0000_0000_0000 == 0x7E00_0000_0000_0000;
+      let payload = ((x.w[HIGH] & 0x0000_3FFF_FFFF_FFFF) as u128) << 64 | x.w[LOW] as u128;
+      Parts::NaN(sign, signaling, if payload > MAX_PAYLOAD { 0 } else { payload })
+    }
+  }
+}
+
+/// Returns canonical 128-bit decimal composed from the sign, coefficient and exponent.
+/// Parts out of range are reported as errors, the value is never rounded.
+pub fn bid128_from_parts(parts: Parts) -> Result<BID128, PartsError> {
+  match parts {
+    Parts::Finite(sign, coefficient, exponent) => {
+      if coefficient > MAX_COEFFICIENT {
+        return Err(PartsError::CoefficientOutOfRange);
+      }
+      if !(MIN_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+        return Err(PartsError::ExponentOutOfRange);
+      }
+      Ok(encode(sign, coefficient, exponent))
+    }
+    Parts::Infinite(sign) => Ok(special(sign, 0x7800_0000_0000_0000, 0)),
+    Parts::NaN(sign, signaling, payload) => {
+      if payload > MAX_PAYLOAD {
+        return Err(PartsError::CoefficientOutOfRange);
+      }
+      let mask = if signaling { 0x7E00_0000_0000_0000 } else { 0x7C00_0000_0000_0000 };
+      Ok(special(sign, mask, payload))
+    }
+  }
+}
+
+/// Returns the special value with the sign, combination field bits and the payload.
+fn special(sign: bool, mask: u64, payload: u128) -> BID128 {
+  let mut x = encode(false, 0, 0);
+  x.w[HIGH] = (sign as u64) << 63 | mask | (payload >> 64) as u64;
+  x.w[LOW] = payload as u64;
+  x
+}
@@ -0,0 +1,203 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Operations on slices of 128-bit decimals
+//!
+//! Exception status flags are accumulated over the whole batch and reported once,
+//! so the flags returned from an operation on slices are the union of flags
+//! raised for all elements. Element-wise operations are run in parallel
+//! when the `rayon` feature is set. Sums and dot products are always calculated
+//! from left to right, so their results do not depend on the number of threads.
+
+use crate::bid128::encode;
+use crate::{
+  bid128_add, bid128_fma, bid128_mul, bid128_quiet_equal, bid128_quiet_greater, bid128_quiet_greater_equal, bid128_quiet_less, bid128_quiet_less_equal, bid128_scalbn_round,
+  bid128_sub, BID128,
+};
+
+/// Number of elements processed by a single task when running in parallel.
+#[cfg(feature = "rayon")]
+const CHUNK_LENGTH: usize = 1024;
+
+/// Relation between decimals checked by [bid128_compare_into_mask].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Comparison {
+  /// `x == y`
+  Equal,
+  /// `x != y`, always true when any of the operands is NaN.
+  NotEqual,
+  /// `x < y`
+  Less,
+  /// `x <= y`
+  LessEqual,
+  /// `x > y`
+  Greater,
+  /// `x >= y`
+  GreaterEqual,
+}
+
+/// Adds decimals element by element, `out[i] = x[i] + y[i]`, and returns raised exception flags.
+/// Panics when the slices have different lengths.
+pub fn bid128_add_slices(x: &[BID128], y: &[BID128], out: &mut [BID128], round: u32) -> u32 {
+  zip_with(x, y, out, |x, y, flags| bid128_add(x, y, round, flags))
+}
+
+/// Subtracts decimals element by element, `out[i] = x[i] - y[i]`, and returns raised exception flags.
+/// Panics when the slices have different lengths.
+pub fn bid128_sub_slices(x: &[BID128], y: &[BID128], out: &mut [BID128], round: u32) -> u32 {
+  zip_with(x, y, out, |x, y, flags| bid128_sub(x, y, round, flags))
+}
+
+/// Multiplies decimals element by element, `out[i] = x[i] * y[i]`, and returns raised exception flags.
+/// Panics when the slices have different lengths.
+pub fn bid128_mul_slices(x: &[BID128], y: &[BID128], out: &mut [BID128], round: u32) -> u32 {
+  zip_with(x, y, out, |x, y, flags| bid128_mul(x, y, round, flags))
+}
+
+/// Multiplies decimals by the power of ten, `out[i] = x[i] * 10^n`, and returns raised exception flags.
+/// Panics when the slices have different lengths.
+pub fn bid128_scale_by_pow10(x: &[BID128], n: i32, out: &mut [BID128], round: u32) -> u32 {
+  map_with(x, out, |x, flags| bid128_scalbn_round(x, n, round, flags))
+}
+
+/// Compares decimals element by element, `mask[i] = x[i] <relation> y[i]`, and returns raised exception flags.
+/// Comparisons are quiet, the invalid exception is signaled only for signaling NaNs.
+/// Panics when the slices have different lengths.
+pub fn bid128_compare_into_mask(x: &[BID128], y: &[BID128], relation: Comparison, mask: &mut [bool]) -> u32 {
+  let compare = match relation {
+    Comparison::Equal => bid128_quiet_equal,
+    Comparison::NotEqual => |x, y, flags: &mut u32| !bid128_quiet_equal(x, y, flags),
+    Comparison::Less => bid128_quiet_less,
+    Comparison::LessEqual => bid128_quiet_less_equal,
+    Comparison::Greater => bid128_quiet_greater,
+    Comparison::GreaterEqual => bid128_quiet_greater_equal,
+  };
+  zip_with(x, y, mask, compare)
+}
+
+/// Returns the sum of all decimals, added from left to right.
+/// Each addition may be rounded, use [ExactAccumulator](crate::ExactAccumulator) to round the sum only once.
+/// The sum of an empty slice is `+0E+0`.
+pub fn bid128_sum(x: &[BID128], round: u32, flags: &mut u32) -> BID128 {
+  let Some((first, rest)) = x.split_first() else {
+    return encode(false, 0, 0);
+  };
+  let mut batch_flags = 0;
+  let sum = rest.iter().fold(*first, |sum, x| bid128_add(sum, *x, round, &mut batch_flags));
+  *flags |= batch_flags;
+  sum
+}
+
+/// Returns the dot product of two slices, `x[0] * y[0] + x[1] * y[1] + ...`,
+/// accumulated from left to right with fused multiply-add, so every term is rounded only once.
+/// The dot product of empty slices is `+0E+0`.
+/// Panics when the slices have different lengths.
+pub fn bid128_dot(x: &[BID128], y: &[BID128], round: u32, flags: &mut u32) -> BID128 {
+  assert_eq!(x.len(), y.len(), "slices have different lengths");
+  if x.is_empty() {
+    return encode(false, 0, 0);
+  }
+  let mut batch_flags = 0;
+  let first = bid128_mul(x[0], y[0], round, &mut batch_flags);
+  let dot = x[1..].iter().zip(&y[1..]).fold(first, |dot, (x, y)| bid128_fma(*x, *y, dot, round, &mut batch_flags));
+  *flags |= batch_flags;
+  dot
+}
+
+/// Applies the operation to elements and stores results in the output slice,
+/// returns the union of exception flags raised for all elements.
+#[cfg(not(feature = "rayon"))]
+fn map_with<T, F>(x: &[BID128], out: &mut [T], f: F) -> u32
+where
+  F: Fn(BID128, &mut u32) -> T,
+{
+  assert_eq!(x.len(), out.len(), "slices have different lengths");
+  let mut flags = 0;
+  for (x, out) in x.iter().zip(out) {
+    *out = f(*x, &mut flags);
+  }
+  flags
+}
+
+/// Applies the operation to elements and stores results in the output slice,
+/// returns the union of exception flags raised for all elements.
+#[cfg(feature = "rayon")]
+fn map_with<T, F>(x: &[BID128], out: &mut [T], f: F) -> u32
+where
+  T: Send,
+  F: Fn(BID128, &mut u32) -> T + Sync,
+{
+  use rayon::prelude::*;
+  assert_eq!(x.len(), out.len(), "slices have different lengths");
+  x.par_chunks(CHUNK_LENGTH)
+    .zip(out.par_chunks_mut(CHUNK_LENGTH))
+    .map(|(x, out)| {
+      let mut flags = 0;
+      for (x, out) in x.iter().zip(out) {
+        *out = f(*x, &mut flags);
+      }
+      flags
+    })
+    .reduce(|| 0, |a, b| a | b)
+}
+
+/// Applies the operation to pairs of elements and stores results in the output slice,
+/// returns the union of exception flags raised for all elements.
+#[cfg(not(feature = "rayon"))]
+fn zip_with<T, F>(x: &[BID128], y: &[BID128], out: &mut [T], f: F) -> u32
+where
+  F: Fn(BID128, BID128, &mut u32) -> T,
+{
+  assert_eq!(x.len(), y.len(), "slices have different lengths");
+  assert_eq!(x.len(), out.len(), "slices have different lengths");
+  let mut flags = 0;
+  for ((x, y), out) in x.iter().zip(y).zip(out) {
+    *out = f(*x, *y, &mut flags);
+  }
+  flags
+}
+
+/// Applies the operation to pairs of elements and stores results in the output slice,
+/// returns the union of exception flags raised for all elements.
+#[cfg(feature = "rayon")]
+fn zip_with<T, F>(x: &[BID128], y: &[BID128], out: &mut [T], f: F) -> u32
+where
+  T: Send,
+  F: Fn(BID128, BID128, &mut u32) -> T + Sync,
+{
+  use rayon::prelude::*;
+  assert_eq!(x.len(), y.len(), "slices have different lengths");
+  assert_eq!(x.len(), out.len(), "slices have different lengths");
+  x.par_chunks(CHUNK_LENGTH)
+    .zip(y.par_chunks(CHUNK_LENGTH))
+    .zip(out.par_chunks_mut(CHUNK_LENGTH))
+    .map(|((x, y), out)| {
+      let mut flags = 0;
+      for ((x, y), out) in x.iter().zip(y).zip(out) {
+        *out = f(*x, *y, &mut flags);
+      }
+      flags
+    })
+    .reduce(|| 0, |a, b| a | b)
+}
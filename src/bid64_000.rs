@@ -0,0 +1,198 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Bindings for 64-bit decimal floating-point functions.
+//!
+//! 000:
+//! - 0 arguments passed by value,
+//! - 0 rounding mode passed as argument,
+//! - 0 pointer to status flags passed as argument.
+
+use crate::common::{copy_to_buffer, FROM_BYTES_BUFFER_LENGTH};
+use crate::{BID128, BID32, BID64, FB_INVALID};
+use core::ffi::{c_char, c_int, c_longlong, c_uint};
+use core::fmt;
+
+/// Maximum length of 64-bit decimal converted to string, including the terminating NUL character,
+/// e.g. `-9999999999999999E-398`.
+pub const BID64_STRING_LENGTH: usize = 23;
+
+#[rustfmt::skip]
+extern "C" {
+  fn __bid64_add(x: BID64, y: BID64, round: c_uint, flags: *mut c_uint) -> BID64;
+  fn __bid64_div(x: BID64, y: BID64, round: c_uint, flags: *mut c_uint) -> BID64;
+  fn __bid64_from_int32(x: c_int) -> BID64;
+  fn __bid64_from_int64(x: c_longlong, round: c_uint, flags: *mut c_uint) -> BID64;
+  fn __bid64_from_string(s: *const c_char, round: c_uint, flags: *mut c_uint) -> BID64;
+  fn __bid64_mul(x: BID64, y: BID64, round: c_uint, flags: *mut c_uint) -> BID64;
+  #[cfg(feature = "transcendental")]
+  fn __bid64_pow(x: BID64, y: BID64, round: c_uint, flags: *mut c_uint) -> BID64;
+  fn __bid64_quantize(x: BID64, y: BID64, round: c_uint, flags: *mut c_uint) -> BID64;
+  fn __bid64_quiet_equal(x: BID64, y: BID64, flags: *mut c_uint) -> c_int;
+  fn __bid64_quiet_less(x: BID64, y: BID64, flags: *mut c_uint) -> c_int;
+  fn __bid64_sqrt(x: BID64, round: c_uint, flags: *mut c_uint) -> BID64;
+  fn __bid64_sub(x: BID64, y: BID64, round: c_uint, flags: *mut c_uint) -> BID64;
+  fn __bid64_to_bid128(x: BID64, flags: *mut c_uint) -> BID128;
+  fn __bid64_to_bid32(x: BID64, round: c_uint, flags: *mut c_uint) -> BID32;
+  fn __bid64_to_int32_int(x: BID64, flags: *mut c_uint) -> c_int;
+  fn __bid64_to_int64_int(x: BID64, flags: *mut c_uint) -> c_longlong;
+  fn __bid64_to_string(s: *mut c_char, x: BID64, flags: *mut c_uint);
+}
+
+/// Returns a result of decimal floating-point addition, [BID64] + [BID64] -> [BID64]
+pub fn bid64_add(x: BID64, y: BID64, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid64_add(x, y, round, flags) }
+}
+
+/// Returns a result of decimal floating-point division, [BID64] / [BID64] -> [BID64]
+pub fn bid64_div(x: BID64, y: BID64, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid64_div(x, y, round, flags) }
+}
+
+/// Converts 32-bit signed integer to 64-bit decimal floating-point number.
+pub fn bid64_from_int32(x: i32) -> BID64 {
+  unsafe { __bid64_from_int32(x) }
+}
+
+/// Converts 64-bit signed integer to 64-bit decimal floating-point number.
+pub fn bid64_from_int64(x: i64, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid64_from_int64(x, round, flags) }
+}
+
+/// Converts a decimal floating-point value represented in string format (decimal character sequence)
+/// to 64-bit decimal floating-point format (binary encoding).
+/// Malformed input, including strings with interior NUL characters, is converted to NaN.
+/// The length of the string is limited like in [bid64_from_bytes].
+pub fn bid64_from_string(s: &str, round: u32, flags: &mut u32) -> BID64 {
+  bid64_from_bytes(s.as_bytes(), round, flags)
+}
+
+/// Converts a decimal floating-point value represented as a sequence of ASCII characters,
+/// not terminated with NUL, to 64-bit decimal floating-point format (binary encoding).
+/// Malformed input, including sequences with NUL characters, is converted to NaN.
+/// Sequences are converted without heap allocation, so after skipping leading blanks
+/// and redundant leading zeros they must be shorter than 128 bytes,
+/// longer sequences are converted to NaN and the `Invalid` exception flag is set.
+pub fn bid64_from_bytes(bytes: &[u8], round: u32, flags: &mut u32) -> BID64 {
+  if bytes.contains(&0) {
+    return unsafe { __bid64_from_string(c"NaN".as_ptr(), round, flags) };
+  }
+  let mut buf = [0_u8; FROM_BYTES_BUFFER_LENGTH];
+  if !copy_to_buffer(bytes, &mut buf) {
+    *flags |= FB_INVALID;
+    return unsafe { __bid64_from_string(c"NaN".as_ptr(), round, flags) };
+  }
+  unsafe { __bid64_from_string(buf.as_ptr() as *const c_char, round, flags) }
+}
+
+/// Returns a result of decimal floating-point multiplication, [BID64] * [BID64] -> [BID64]
+pub fn bid64_mul(x: BID64, y: BID64, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid64_mul(x, y, round, flags) }
+}
+
+/// Returns the value of `x` raised to the power of `y`.
+#[cfg(feature = "transcendental")]
+pub fn bid64_pow(x: BID64, y: BID64, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid64_pow(x, y, round, flags) }
+}
+
+/// Returns the number which is equal in value (except for any rounding) and sign
+/// to the first (left-hand) operand and which has an exponent set to be equal
+/// to the exponent of the second (right-hand) operand.
+pub fn bid64_quantize(x: BID64, y: BID64, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid64_quantize(x, y, round, flags) }
+}
+
+/// Compares 64-bit decimal floating-point numbers for specified relation,
+/// does not signal invalid exception for quiet NaNs.
+pub fn bid64_quiet_equal(x: BID64, y: BID64, flags: &mut u32) -> bool {
+  unsafe { __bid64_quiet_equal(x, y, flags) != 0 }
+}
+
+/// Compares 64-bit decimal floating-point numbers for specified relation,
+/// does not signal invalid exception for quiet NaNs.
+pub fn bid64_quiet_less(x: BID64, y: BID64, flags: &mut u32) -> bool {
+  unsafe { __bid64_quiet_less(x, y, flags) != 0 }
+}
+
+/// Returns decimal floating-point square root.
+pub fn bid64_sqrt(x: BID64, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid64_sqrt(x, round, flags) }
+}
+
+/// Returns a result of decimal floating-point subtraction, [BID64] - [BID64] -> [BID64]
+pub fn bid64_sub(x: BID64, y: BID64, round: u32, flags: &mut u32) -> BID64 {
+  unsafe { __bid64_sub(x, y, round, flags) }
+}
+
+/// Converts 64-bit decimal floating-point value to 128-bit decimal floating-point value, the conversion is always exact.
+pub fn bid64_to_bid128(x: BID64, flags: &mut u32) -> BID128 {
+  unsafe { __bid64_to_bid128(x, flags) }
+}
+
+/// Converts 64-bit decimal floating-point value to 32-bit decimal floating-point value,
+/// rounded according to the specified rounding mode.
+pub fn bid64_to_bid32(x: BID64, round: u32, flags: &mut u32) -> BID32 {
+  unsafe { __bid64_to_bid32(x, round, flags) }
+}
+
+/// Convert 64-bit decimal floating-point value to 32-bit signed integer
+/// with rounding-to-zero mode, inexact exceptions are not signaled.
+pub fn bid64_to_int32_int(x: BID64, flags: &mut u32) -> i32 {
+  unsafe { __bid64_to_int32_int(x, flags) }
+}
+
+/// Convert 64-bit decimal floating-point value to 64-bit signed integer
+/// with rounding-to-zero mode, inexact exceptions are not signaled.
+pub fn bid64_to_int64_int(x: BID64, flags: &mut u32) -> i64 {
+  unsafe { __bid64_to_int64_int(x, flags) }
+}
+
+/// Converts 64-bit decimal floating-point value (binary encoding)
+/// to string format (decimal character sequence).
+#[cfg(feature = "std")]
+pub fn bid64_to_string(x: BID64, flags: &mut u32) -> String {
+  let mut buf = [0_u8; BID64_STRING_LENGTH];
+  bid64_to_str_buf(x, &mut buf, flags).to_string()
+}
+
+/// Converts 64-bit decimal floating-point value (binary encoding)
+/// to string format (decimal character sequence) stored in the provided buffer.
+/// The buffer must be at least [BID64_STRING_LENGTH] bytes long, this is checked at compile time.
+pub fn bid64_to_str_buf<'a, const N: usize>(x: BID64, buf: &'a mut [u8; N], flags: &mut u32) -> &'a str {
+  const { assert!(N >= BID64_STRING_LENGTH, "buffer is too short") };
+  unsafe {
+    __bid64_to_string(buf.as_mut_ptr() as *mut c_char, x, flags);
+  }
+  let len = buf.iter().position(|b| *b == 0).unwrap_or(N);
+  // the library writes only ASCII characters
+  unsafe { core::str::from_utf8_unchecked(&buf[..len]) }
+}
+
+/// Writes 64-bit decimal floating-point value (binary encoding)
+/// in string format (decimal character sequence) without allocating memory.
+pub fn bid64_write_to(x: BID64, w: &mut impl fmt::Write, flags: &mut u32) -> fmt::Result {
+  let mut buf = [0_u8; BID64_STRING_LENGTH];
+  w.write_str(bid64_to_str_buf(x, &mut buf, flags))
+}
@@ -0,0 +1,77 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Conversions between binary integer decimal (BID) and densely packed decimal (DPD) encodings.
+//!
+//! 000:
+//! - 0 arguments passed by value,
+//! - 0 rounding mode passed as argument,
+//! - 0 pointer to status flags passed as argument.
+
+use crate::bid128::{HIGH, LOW};
+use crate::{BID128, BID32, BID64};
+
+#[rustfmt::skip]
+extern "C" {
+  fn __bid_dpd_to_bid128(x: BID128) -> BID128;
+  fn __bid_dpd_to_bid32(x: u32) -> BID32;
+  fn __bid_dpd_to_bid64(x: u64) -> BID64;
+  fn __bid_to_dpd128(x: BID128) -> BID128;
+  fn __bid_to_dpd32(x: BID32) -> u32;
+  fn __bid_to_dpd64(x: BID64) -> u64;
+}
+
+/// Converts 128-bit decimal in DPD encoding to [BID128].
+pub fn bid_dpd_to_bid128(x: u128) -> BID128 {
+  let mut w = [0; 2];
+  w[HIGH] = (x >> 64) as u64;
+  w[LOW] = x as u64;
+  unsafe { __bid_dpd_to_bid128(BID128 { w }) }
+}
+
+/// Converts 32-bit decimal in DPD encoding to [BID32].
+pub fn bid_dpd_to_bid32(x: u32) -> BID32 {
+  unsafe { __bid_dpd_to_bid32(x) }
+}
+
+/// Converts 64-bit decimal in DPD encoding to [BID64].
+pub fn bid_dpd_to_bid64(x: u64) -> BID64 {
+  unsafe { __bid_dpd_to_bid64(x) }
+}
+
+/// Converts [BID128] to 128-bit decimal in DPD encoding.
+pub fn bid_to_dpd128(x: BID128) -> u128 {
+  let w = unsafe { __bid_to_dpd128(x) }.w;
+  (w[HIGH] as u128) << 64 | w[LOW] as u128
+}
+
+/// Converts [BID32] to 32-bit decimal in DPD encoding.
+pub fn bid_to_dpd32(x: BID32) -> u32 {
+  unsafe { __bid_to_dpd32(x) }
+}
+
+/// Converts [BID64] to 64-bit decimal in DPD encoding.
+pub fn bid_to_dpd64(x: BID64) -> u64 {
+  unsafe { __bid_to_dpd64(x) }
+}
@@ -0,0 +1,107 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Alternate exception handling with exception masks.
+//!
+//! Every exception raised by an operation is either masked, then only its status flag is raised,
+//! or unmasked, then the operation is trapped and the exception is reported as [DecimalException].
+//! Exception masks are thread-local, like status flags stored in global variables, and initially
+//! all exceptions are masked, so operations behave as if there was no alternate handling.
+//!
+//! The masks are examined by [bid_trap] and [bid_trap_or_panic] wrapping calls to operations,
+//! because no function in the Intel library ever reads the exception masks. Setting
+//! `DECIMAL_ALTERNATE_EXCEPTION_HANDLING` only adds a masks argument to every function,
+//! and ten sources, e.g. `bid128_fma.c` and the `strtod`/`wcstod` conversions, do not compile with it.
+//! The masks do not depend on how rounding modes and status flags are passed to the library.
+
+use crate::{FB_CLEAR, FB_INEXACT, FB_INVALID, FB_OVERFLOW, FB_UNDERFLOW, FB_ZERO_DIVIDE};
+use std::cell::Cell;
+use std::fmt;
+
+/// Exception masks with all exceptions masked, the initial value of exception masks in every thread.
+pub const EM_ALL: u32 = FB_INVALID | FB_ZERO_DIVIDE | FB_OVERFLOW | FB_UNDERFLOW | FB_INEXACT;
+
+thread_local! {
+  static EXCEPTION_MASKS: Cell<u32> = const { Cell::new(EM_ALL) };
+}
+
+/// Unmasked exceptions raised by a trapped operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DecimalException {
+  /// Unmasked exceptions raised by the operation, as `FB_*` bits.
+  pub unmasked: u32,
+  /// All exceptions raised by the operation, including masked ones.
+  pub raised: u32,
+}
+
+impl fmt::Display for DecimalException {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let names = [
+      (FB_INVALID, "invalid operation"),
+      (FB_ZERO_DIVIDE, "division by zero"),
+      (FB_OVERFLOW, "overflow"),
+      (FB_UNDERFLOW, "underflow"),
+      (FB_INEXACT, "inexact result"),
+    ];
+    let mut names = names.iter().filter(|(flag, _)| self.unmasked & flag != 0).map(|(_, name)| *name);
+    write!(f, "decimal exception: {}", names.next().unwrap_or("none"))?;
+    names.try_for_each(|name| write!(f, ", {name}"))
+  }
+}
+
+impl std::error::Error for DecimalException {}
+
+/// Returns exception masks of the current thread, a set `FB_*` bit masks the exception.
+pub fn bid_get_exception_masks() -> u32 {
+  EXCEPTION_MASKS.with(Cell::get)
+}
+
+/// Sets exception masks of the current thread, a set `FB_*` bit masks the exception,
+/// and returns previous masks, so they can be restored.
+pub fn bid_set_exception_masks(masks: u32) -> u32 {
+  EXCEPTION_MASKS.with(|cell| cell.replace(masks & EM_ALL))
+}
+
+/// Runs the operation `f` with clear status flags and checks raised exceptions against
+/// exception masks of the current thread. Masked exceptions raise status flags in `flags`,
+/// unmasked exceptions are returned as an error and do not change `flags`.
+pub fn bid_trap<T>(flags: &mut u32, f: impl FnOnce(&mut u32) -> T) -> Result<T, DecimalException> {
+  let mut raised = FB_CLEAR;
+  let value = f(&mut raised);
+  let unmasked = raised & !bid_get_exception_masks() & EM_ALL;
+  if unmasked != FB_CLEAR {
+    return Err(DecimalException { unmasked, raised });
+  }
+  *flags |= raised;
+  Ok(value)
+}
+
+/// Runs the operation `f` like [bid_trap], but panics when unmasked exceptions are raised.
+#[track_caller]
+pub fn bid_trap_or_panic<T>(flags: &mut u32, f: impl FnOnce(&mut u32) -> T) -> T {
+  match bid_trap(flags, f) {
+    Ok(value) => value,
+    Err(exception) => panic!("{exception}"),
+  }
+}
@@ -0,0 +1,99 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Rounding mode and exception status flags stored in global variables.
+//!
+//! When `global-rounding` or `global-exception-flags` feature is set, operations of the library
+//! take the rounding mode from, and raise exception flags in, global variables instead of arguments.
+//! These variables are thread-local, so every thread has its own rounding mode, initially rounding
+//! to nearest even, and its own exception flags, initially clear. Threads never race on them,
+//! but the state does not follow the values either: decimals are plain [Copy] values, which are
+//! [Send] and [Sync], while the rounding mode and flags belong to the thread running the operation.
+//! Tasks that may be moved between threads, like futures or jobs of a thread pool, should set
+//! the rounding mode and read the flags within the same synchronous part of their work.
+//!
+//! 000:
+//! - 0 arguments passed by value,
+//! - 1 rounding mode stored in a global variable when `global-rounding` feature is set,
+//! - 1 status flags stored in a global variable when `global-exception-flags` feature is set.
+
+#[rustfmt::skip]
+extern "C" {
+  #[cfg(feature = "global-rounding")]
+  fn __bid_getDecimalRoundingDirection() -> u32;
+  #[cfg(feature = "global-exception-flags")]
+  fn __bid_lowerFlags(mask: u32);
+  #[cfg(feature = "global-exception-flags")]
+  fn __bid_restoreFlags(values: u32, mask: u32);
+  #[cfg(feature = "global-exception-flags")]
+  fn __bid_saveFlags(mask: u32) -> u32;
+  #[cfg(feature = "global-rounding")]
+  fn __bid_setDecimalRoundingDirection(round: u32);
+  #[cfg(feature = "global-exception-flags")]
+  fn __bid_signalException(mask: u32);
+  #[cfg(feature = "global-exception-flags")]
+  fn __bid_testFlags(mask: u32) -> u32;
+}
+
+/// Returns the rounding mode of the current thread, one of `RM_*` constants.
+#[cfg(feature = "global-rounding")]
+pub fn bid_get_decimal_rounding_direction() -> u32 {
+  unsafe { __bid_getDecimalRoundingDirection() }
+}
+
+/// Sets the rounding mode of the current thread, one of `RM_*` constants.
+#[cfg(feature = "global-rounding")]
+pub fn bid_set_decimal_rounding_direction(round: u32) {
+  unsafe { __bid_setDecimalRoundingDirection(round) }
+}
+
+/// Clears exception flags of the current thread selected by `mask`.
+#[cfg(feature = "global-exception-flags")]
+pub fn bid_lower_flags(mask: u32) {
+  unsafe { __bid_lowerFlags(mask) }
+}
+
+/// Sets exception flags of the current thread selected by `mask` to `values`, other flags are left unchanged.
+#[cfg(feature = "global-exception-flags")]
+pub fn bid_restore_flags(values: u32, mask: u32) {
+  unsafe { __bid_restoreFlags(values, mask) }
+}
+
+/// Returns exception flags of the current thread selected by `mask`, to be restored later with [bid_restore_flags].
+#[cfg(feature = "global-exception-flags")]
+pub fn bid_save_flags(mask: u32) -> u32 {
+  unsafe { __bid_saveFlags(mask) }
+}
+
+/// Raises exception flags of the current thread selected by `mask`.
+#[cfg(feature = "global-exception-flags")]
+pub fn bid_signal_exception(mask: u32) {
+  unsafe { __bid_signalException(mask) }
+}
+
+/// Returns exception flags of the current thread selected by `mask`, that are raised.
+#[cfg(feature = "global-exception-flags")]
+pub fn bid_test_flags(mask: u32) -> u32 {
+  unsafe { __bid_testFlags(mask) }
+}
@@ -31,6 +31,51 @@ pub struct BID128 {
   pub w: [u64; 2],
 }
 
+/// 64-bit decimal.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone)]
+pub struct BID64 {
+  pub w: u64,
+}
+
+/// 32-bit decimal.
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone)]
+pub struct BID32 {
+  pub w: u32,
+}
+
+/// Length of the stack buffer used for converting byte sequences to decimals.
+#[allow(dead_code)] // unused when bindings of all formats are disabled
+pub(crate) const FROM_BYTES_BUFFER_LENGTH: usize = 128;
+
+/// Copies the sequence of ASCII characters to the buffer as NUL-terminated string, without leading blanks
+/// and redundant leading zeros, which are skipped by the Intel library anyway, so long sequences
+/// are converted the same way with and without the `std` feature.
+/// Returns `false` when the remaining characters do not fit in the buffer.
+#[allow(dead_code)] // unused when bindings of all formats are disabled
+pub(crate) fn copy_to_buffer(bytes: &[u8], buf: &mut [u8; FROM_BYTES_BUFFER_LENGTH]) -> bool {
+  let blanks = bytes.iter().take_while(|b| matches!(b, b' ' | b'\t')).count();
+  let (sign, rest) = match bytes[blanks..].split_first() {
+    Some((sign @ (b'+' | b'-'), rest)) => (Some(*sign), rest),
+    _ => (None, &bytes[blanks..]),
+  };
+  // a zero followed by another digit changes neither the value nor the exponent
+  let zeros = rest.windows(2).take_while(|pair| pair[0] == b'0' && pair[1].is_ascii_digit()).count();
+  let rest = &rest[zeros..];
+  let start = sign.is_some() as usize;
+  let end = start + rest.len();
+  if end >= FROM_BYTES_BUFFER_LENGTH {
+    return false;
+  }
+  if let Some(sign) = sign {
+    buf[0] = sign;
+  }
+  buf[start..end].copy_from_slice(rest);
+  buf[end] = 0;
+  true
+}
+
 /// Exception flag `Invalid` as [u32] value.
 pub const FB_INVALID: u32 = FlagBits::Invalid as u32;
 /// Exception flag `ZeroDivide` as [u32] value.
@@ -67,6 +112,7 @@ pub const RM_TOWARD_ZERO: u32 = RoundingModes::TowardZero as u32;
 pub const RM_NEAREST_AWAY: u32 = RoundingModes::NearestAway as u32;
 
 /// Rounding modes.
+#[cfg_attr(feature = "wasm-bindgen", wasm_bindgen::prelude::wasm_bindgen)]
 #[repr(u32)]
 pub enum RoundingModes {
   NearestEven = 0,
@@ -23,15 +23,61 @@
  */
 
 //! # Rust bindings for Intel(R) Decimal Floating-Point Math Library v2.2
+//!
+//! The crate is `no_std` when the default `std` feature is not set. Arithmetic, comparisons
+//! and conversions are then still available, decimals are converted from and to strings
+//! using byte slices and fixed-size buffers, e.g. [bid128_from_bytes] and [bid128_to_str_buf].
 
-extern crate libc;
+#![cfg_attr(not(feature = "std"), no_std)]
 
 mod bid128;
-#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+#[cfg(all(dfp_by_value, feature = "bid128"))]
 mod bid128_000;
+#[cfg(all(dfp_by_value, feature = "std", feature = "bid128"))]
+mod bid128_accumulator;
+#[cfg(feature = "std")]
+mod bid128_format;
+#[cfg(all(dfp_by_value, feature = "std", feature = "bid128"))]
+mod bid128_parse;
+mod bid128_parts;
+#[cfg(all(dfp_by_value, feature = "bid128"))]
+mod bid128_slice;
+#[cfg(all(dfp_by_value, feature = "bid32"))]
+mod bid32_000;
+#[cfg(all(dfp_by_value, feature = "bid64"))]
+mod bid64_000;
+#[cfg(all(dfp_by_value, feature = "dpd"))]
+mod bid_dpd;
+#[cfg(feature = "alternate-exception-handling")]
+mod bid_exceptions;
+#[cfg(all(any(feature = "global-rounding", feature = "global-exception-flags"), not(feature = "call-by-reference")))]
+mod bid_globals;
 mod common;
+#[cfg(all(dfp_by_value, feature = "bid128", feature = "wasm-bindgen"))]
+mod wasm;
 
 pub use bid128::*;
-#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+#[cfg(all(dfp_by_value, feature = "bid128"))]
 pub use bid128_000::*;
+#[cfg(all(dfp_by_value, feature = "std", feature = "bid128"))]
+pub use bid128_accumulator::*;
+#[cfg(feature = "std")]
+pub use bid128_format::*;
+#[cfg(all(dfp_by_value, feature = "std", feature = "bid128"))]
+pub use bid128_parse::*;
+pub use bid128_parts::*;
+#[cfg(all(dfp_by_value, feature = "bid128"))]
+pub use bid128_slice::*;
+#[cfg(all(dfp_by_value, feature = "bid32"))]
+pub use bid32_000::*;
+#[cfg(all(dfp_by_value, feature = "bid64"))]
+pub use bid64_000::*;
+#[cfg(all(dfp_by_value, feature = "dpd"))]
+pub use bid_dpd::*;
+#[cfg(feature = "alternate-exception-handling")]
+pub use bid_exceptions::*;
+#[cfg(all(any(feature = "global-rounding", feature = "global-exception-flags"), not(feature = "call-by-reference")))]
+pub use bid_globals::*;
 pub use common::*;
+#[cfg(all(dfp_by_value, feature = "bid128", feature = "wasm-bindgen"))]
+pub use wasm::*;
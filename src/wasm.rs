@@ -0,0 +1,180 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! Bindings of 128-bit decimals for JavaScript, generated with `wasm-bindgen`.
+//!
+//! Decimals are exposed as immutable `Decimal` class, e.g.:
+//!
+//! ```js
+//! const total = new Decimal("0.1").add(new Decimal("0.2"));
+//! total.toString(); // "0.3"
+//! total.mul(new Decimal("3")).toFixed(2); // "0.90"
+//! ```
+
+use crate::bid128::{encode, MAX_EXPONENT, MIN_EXPONENT};
+use crate::{
+  bid128_abs, bid128_add, bid128_div, bid128_format, bid128_from_int32, bid128_is_finite, bid128_is_signed, bid128_is_zero, bid128_mul, bid128_negate, bid128_parse,
+  bid128_quantize, bid128_quiet_equal, bid128_quiet_less, bid128_sqrt, bid128_sub, FormatOptions, ParseMode, RoundingModes, BID128, FB_CLEAR, FB_INVALID, FB_OVERFLOW,
+  FB_ZERO_DIVIDE, RM_NEAREST_EVEN,
+};
+use wasm_bindgen::prelude::*;
+
+/// 128-bit decimal floating-point number with 34 significant digits.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone)]
+pub struct Decimal(BID128);
+
+impl Decimal {
+  /// Returns the decimal calculated by the operation, or an error when any of
+  /// invalid operation, division by zero or overflow was signaled.
+  fn checked(operation: impl FnOnce(&mut u32) -> BID128) -> Result<Decimal, JsError> {
+    let mut flags = FB_CLEAR;
+    let x = operation(&mut flags);
+    if flags & FB_INVALID != 0 {
+      Err(JsError::new("invalid operation"))
+    } else if flags & FB_ZERO_DIVIDE != 0 {
+      Err(JsError::new("division by zero"))
+    } else if flags & FB_OVERFLOW != 0 {
+      Err(JsError::new("overflow"))
+    } else {
+      Ok(Decimal(x))
+    }
+  }
+}
+
+#[wasm_bindgen]
+impl Decimal {
+  /// Parses the decimal from string, like `"-12.50"` or `"1e-3"`.
+  /// Values that can not be represented exactly are rejected.
+  #[wasm_bindgen(constructor)]
+  pub fn new(value: &str) -> Result<Decimal, JsError> {
+    bid128_parse(value, ParseMode::Strict, RM_NEAREST_EVEN, &mut FB_CLEAR.clone())
+      .map(Decimal)
+      .map_err(|e| JsError::new(&e.to_string()))
+  }
+
+  /// Converts the integer to decimal.
+  #[wasm_bindgen(js_name = fromInt)]
+  pub fn from_int(value: i32) -> Decimal {
+    Decimal(bid128_from_int32(value))
+  }
+
+  /// Returns the sum `this + other`.
+  pub fn add(&self, other: &Decimal) -> Result<Decimal, JsError> {
+    Self::checked(|flags| bid128_add(self.0, other.0, RM_NEAREST_EVEN, flags))
+  }
+
+  /// Returns the difference `this - other`.
+  pub fn sub(&self, other: &Decimal) -> Result<Decimal, JsError> {
+    Self::checked(|flags| bid128_sub(self.0, other.0, RM_NEAREST_EVEN, flags))
+  }
+
+  /// Returns the product `this * other`.
+  pub fn mul(&self, other: &Decimal) -> Result<Decimal, JsError> {
+    Self::checked(|flags| bid128_mul(self.0, other.0, RM_NEAREST_EVEN, flags))
+  }
+
+  /// Returns the quotient `this / other`, rounded to 34 significant digits.
+  pub fn div(&self, other: &Decimal) -> Result<Decimal, JsError> {
+    Self::checked(|flags| bid128_div(self.0, other.0, RM_NEAREST_EVEN, flags))
+  }
+
+  /// Returns the square root, rounded to 34 significant digits.
+  pub fn sqrt(&self) -> Result<Decimal, JsError> {
+    Self::checked(|flags| bid128_sqrt(self.0, RM_NEAREST_EVEN, flags))
+  }
+
+  /// Returns the value rounded to specified number of decimal places,
+  /// from -6111 (rounding to a multiple of 10^6111) to 6176.
+  pub fn round(&self, places: i32, mode: RoundingModes) -> Result<Decimal, JsError> {
+    if !(-MAX_EXPONENT..=-MIN_EXPONENT).contains(&places) {
+      return Err(JsError::new("number of decimal places out of range"));
+    }
+    Self::checked(|flags| bid128_quantize(self.0, encode(false, 1, -places), mode as u32, flags))
+  }
+
+  /// Returns the value with reversed sign.
+  pub fn neg(&self) -> Decimal {
+    Decimal(bid128_negate(self.0))
+  }
+
+  /// Returns the absolute value.
+  pub fn abs(&self) -> Decimal {
+    Decimal(bid128_abs(self.0))
+  }
+
+  /// Compares the values, returns -1, 0 or 1, or `undefined` when any of the values is NaN.
+  pub fn compare(&self, other: &Decimal) -> Option<i32> {
+    let mut flags = FB_CLEAR;
+    if bid128_quiet_less(self.0, other.0, &mut flags) {
+      Some(-1)
+    } else if bid128_quiet_equal(self.0, other.0, &mut flags) {
+      Some(0)
+    } else if bid128_quiet_less(other.0, self.0, &mut flags) {
+      Some(1)
+    } else {
+      None
+    }
+  }
+
+  /// Returns `true` when the value is zero.
+  #[wasm_bindgen(js_name = isZero)]
+  pub fn is_zero(&self) -> bool {
+    bid128_is_zero(self.0)
+  }
+
+  /// Returns `true` when the value is negative, including negative zero.
+  #[wasm_bindgen(js_name = isNegative)]
+  pub fn is_negative(&self) -> bool {
+    bid128_is_signed(self.0)
+  }
+
+  /// Returns `true` when the value is neither infinite nor NaN.
+  #[wasm_bindgen(js_name = isFinite)]
+  pub fn is_finite(&self) -> bool {
+    bid128_is_finite(self.0)
+  }
+
+  /// Returns the value in fixed-point notation with all significant digits, like `"1234.5"`.
+  #[wasm_bindgen(js_name = toString)]
+  pub fn to_js_string(&self) -> String {
+    self.0.to_string()
+  }
+
+  /// Returns the value in fixed-point notation with specified number of decimal places,
+  /// rounded to nearest, ties to even. The number of decimal places can not exceed 6176,
+  /// every finite decimal is represented exactly with that many places.
+  #[wasm_bindgen(js_name = toFixed)]
+  pub fn to_fixed(&self, places: usize) -> Result<String, JsError> {
+    if places > -MIN_EXPONENT as usize {
+      return Err(JsError::new("number of decimal places out of range"));
+    }
+    let options = FormatOptions {
+      precision: Some(places),
+      trailing_zeros: true,
+      ..Default::default()
+    };
+    Ok(bid128_format(self.0, options).to_string())
+  }
+}
@@ -30,9 +30,15 @@
 //! │ │ └─ status flags passed as an argument
 //! │ └─── rounding mode passed as an argument
 //! └───── result returned by value
-/// ```
-
-#[cfg(all(not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+//! ```
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
 mod tests_000 {
   use dfp_number_sys::*;
 
@@ -82,6 +88,7 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_exp_0001() {
     let x = bid128_from_int32(0);
     let mut flags = FB_CLEAR;
@@ -91,6 +98,7 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_exp_0002() {
     let x = bid128_from_int32(1);
     let mut flags = FB_CLEAR;
@@ -100,6 +108,7 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_exp_0003() {
     let x = d128("2.5");
     let mut flags = FB_CLEAR;
@@ -108,6 +117,14 @@ mod tests_000 {
     eq("+1218249396070347343807017595116797E-32", z);
   }
 
+  #[test]
+  fn test_bid128_fma() {
+    let mut flags = FB_CLEAR;
+    let z = bid128_fma(d128("2.5"), d128("4"), d128("-0.25"), RM_NEAREST_EVEN, &mut flags);
+    assert_eq!(FB_CLEAR, flags);
+    eq("+975E-2", z);
+  }
+
   #[test]
   fn test_bid128_frexp() {
     let x = d128("25.4300");
@@ -117,6 +134,24 @@ mod tests_000 {
     assert_eq!(2, exp);
   }
 
+  #[test]
+  fn test_bid128_from_bytes() {
+    let line = b"12.50;-0.45985E-6;abc;";
+    let fields: Vec<BID128> = line.split(|b| *b == b';').map(|field| bid128_from_bytes(field, RM_NEAREST_EVEN, f!())).collect();
+    eq("+1250E-2", fields[0]);
+    eq("-45985E-11", fields[1]);
+    eq("+NaN", fields[2]);
+    eq("+NaN", fields[3]);
+    eq("+NaN", bid128_from_bytes(b"1\x002", RM_NEAREST_EVEN, f!()));
+    let long = format!(" -{}1.5", "0".repeat(200));
+    eq("-15E-1", bid128_from_bytes(long.as_bytes(), RM_NEAREST_EVEN, f!()));
+    eq("+NaN", bid128_from_bytes(format!("{long}\0").as_bytes(), RM_NEAREST_EVEN, f!()));
+    let mut flags = FB_CLEAR;
+    let long = format!("0.{}1", "0".repeat(200));
+    eq("+NaN", bid128_from_bytes(long.as_bytes(), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_INVALID, flags);
+  }
+
   #[test]
   fn test_bid128_from_int32() {
     eq("-2147483648E+0", bid128_from_int32(i32::MIN));
@@ -155,6 +190,35 @@ mod tests_000 {
     eq("-12345E-2", x);
   }
 
+  #[test]
+  fn test_bid128_from_string_0003() {
+    let mut flags = FB_CLEAR;
+    let x = bid128_from_string("12\u{0}45", RM_NEAREST_EVEN, &mut flags);
+    assert_eq!(FB_CLEAR, flags);
+    eq("+NaN", x);
+  }
+
+  #[test]
+  fn test_bid_strtod128() {
+    let (x, consumed) = bid_strtod128(" 12.5E-1xyz");
+    eq("+125E-2", x);
+    assert_eq!(8, consumed);
+    let (x, consumed) = bid_strtod128("xyz");
+    eq("+0E+0", x);
+    assert_eq!(0, consumed);
+    let (x, consumed) = bid_strtod128("\x0B\x0C7\u{0}8");
+    eq("+7E+0", x);
+    assert_eq!(3, consumed);
+  }
+
+  #[test]
+  fn test_bid_strtod128_long() {
+    let s = format!("{}1,{}", "0".repeat(200), "2".repeat(1000));
+    let (x, consumed) = bid_strtod128(&s);
+    eq("+1E+0", x);
+    assert_eq!(201, consumed);
+  }
+
   #[test]
   fn test_bid128_from_uint32() {
     eq("+0E+0", bid128_from_uint32(0));
@@ -191,6 +255,7 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_log_0001() {
     let x = bid128_from_int32(0);
     let mut flags = FB_CLEAR;
@@ -200,6 +265,7 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_log_0002() {
     let x = bid128_from_int32(1);
     let mut flags = FB_CLEAR;
@@ -209,6 +275,7 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_log_0003() {
     let x = d128("2.7182818284590452353602874713527");
     let mut flags = FB_CLEAR;
@@ -218,6 +285,7 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_log_0004() {
     let x = d128("10.0");
     let mut flags = FB_CLEAR;
@@ -227,6 +295,7 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_log_0005() {
     let x = d128("+Inf");
     let mut flags = FB_CLEAR;
@@ -306,14 +375,18 @@ mod tests_000 {
   }
 
   #[test]
+  #[cfg(feature = "transcendental")]
   fn test_bid128_pow() {
     eq("+8E+0", bid128_pow(d128("2"), d128("3"), RM_NEAREST_EVEN, f!()));
   }
 
   #[test]
   fn test_bid128_quantexp() {
-    assert_eq!(-4, bid128_quantexp(d128("2.3456")));
-    assert_eq!(-7, bid128_quantexp(d128("122.4567000")));
+    assert_eq!(-4, bid128_quantexp(d128("2.3456"), f!()));
+    assert_eq!(-7, bid128_quantexp(d128("122.4567000"), f!()));
+    let mut flags = FB_CLEAR;
+    assert_eq!(i32::MIN, bid128_quantexp(d128("Inf"), &mut flags));
+    assert_eq!(FB_INVALID, flags);
   }
 
   #[test]
@@ -604,6 +677,17 @@ mod tests_000 {
     assert_eq!(FB_INVALID, flags);
   }
 
+  #[test]
+  fn test_bid128_to_str_buf() {
+    let mut buf = [0_u8; BID128_STRING_LENGTH];
+    assert_eq!("-12345E-2", bid128_to_str_buf(d128("-123.45"), &mut buf, f!()));
+    assert_eq!("+SNaN", bid128_to_str_buf(d128("sNaN"), &mut buf, f!()));
+    let max = "-9999999999999999999999999999999999E-6176";
+    assert_eq!(max, bid128_to_str_buf(d128(max), &mut buf, f!()));
+    let mut buf = [b'x'; 64];
+    assert_eq!("+0E+6111", bid128_to_str_buf(d128("0E+6111"), &mut buf, f!()));
+  }
+
   #[test]
   fn test_bid128_to_uint32_int() {
     assert_eq!(0, bid128_to_uint32_int(d128("0"), f!()));
@@ -649,6 +733,15 @@ mod tests_000 {
     assert_eq!(FB_INVALID, flags);
   }
 
+  #[test]
+  fn test_bid128_write_to() {
+    let mut s = String::from("total=");
+    bid128_write_to(d128("1.50"), &mut s, f!()).unwrap();
+    s.push(';');
+    bid128_write_to(d128("-Inf"), &mut s, f!()).unwrap();
+    assert_eq!("total=+150E-2;-Inf", s);
+  }
+
   #[test]
   fn test_bid128_infinite() {
     let x = bid128_inf();
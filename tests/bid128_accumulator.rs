@@ -0,0 +1,210 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Tests of exact summation
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_accumulator {
+  use dfp_number_sys::*;
+
+  const ROUNDING: [u32; 5] = [RM_NEAREST_EVEN, RM_DOWNWARD, RM_UPWARD, RM_TOWARD_ZERO, RM_NEAREST_AWAY];
+
+  fn d128(s: &str) -> BID128 {
+    let mut flags = FB_CLEAR;
+    bid128_from_string(s, RM_NEAREST_EVEN, &mut flags)
+  }
+
+  fn sum(values: &[&str], round: u32, flags: &mut u32) -> String {
+    let accumulator: ExactAccumulator = values.iter().map(|s| d128(s)).collect();
+    bid128_to_string(accumulator.finish(round, flags), &mut FB_CLEAR.clone())
+  }
+
+  /// Simple xorshift generator, so the tests are repeatable.
+  struct Random(u64);
+
+  impl Random {
+    fn below(&mut self, n: u64) -> u64 {
+      self.0 ^= self.0 << 13;
+      self.0 ^= self.0 >> 7;
+      self.0 ^= self.0 << 17;
+      self.0 % n
+    }
+
+    fn decimal(&mut self) -> BID128 {
+      let mut coefficient = 0_u128;
+      for _ in 0..self.below(35) {
+        coefficient = coefficient * 10 + self.below(10) as u128;
+      }
+      let exponent = match self.below(4) {
+        0 => self.below(12288) as i64 - 6176,
+        1 => 6111 - self.below(80) as i64,
+        _ => self.below(80) as i64 - 40,
+      };
+      let sign = if self.below(2) == 0 { "+" } else { "-" };
+      match self.below(50) {
+        0 => d128("NaN"),
+        1 => d128("SNaN"),
+        2 => d128("-Inf"),
+        3 => d128("Inf"),
+        _ => d128(&format!("{sign}{coefficient}E{exponent}")),
+      }
+    }
+  }
+
+  #[test]
+  fn test_exact_accumulator_empty() {
+    let mut flags = FB_CLEAR;
+    assert_eq!("+0E+0", sum(&[], RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_exact_accumulator_exact() {
+    let mut flags = FB_CLEAR;
+    assert_eq!("+1E+0", sum(&["1E40", "1", "-1E40"], RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(
+      "+1E-2",
+      sum(
+        &["9999999999999999999999999999999999", "0.01", "-9999999999999999999999999999999999"],
+        RM_NEAREST_EVEN,
+        &mut flags
+      )
+    );
+    assert_eq!("-250E-2", sum(&["-1.5", "2", "-3.00"], RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_exact_accumulator_inexact() {
+    let mut flags = FB_CLEAR;
+    assert_eq!(
+      "+1000000000000000000000000000000000E+1",
+      sum(&["9999999999999999999999999999999999", "0.5", "0.5"], RM_NEAREST_EVEN, &mut flags)
+    );
+    assert_eq!(FB_CLEAR, flags);
+    assert_eq!("+1000000000000000000000000000000000E-33", sum(&["1", "1E-40"], RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_INEXACT, flags);
+    let mut flags = FB_CLEAR;
+    assert_eq!("+1000000000000000000000000000000001E-33", sum(&["1", "1E-40"], RM_UPWARD, &mut flags));
+    assert_eq!("-1000000000000000000000000000000001E-33", sum(&["-1", "-1E-40"], RM_DOWNWARD, &mut flags));
+    assert_eq!("+9999999999999999999999999999999999E-34", sum(&["1", "-1E-40"], RM_TOWARD_ZERO, &mut flags));
+    assert_eq!(FB_INEXACT, flags);
+  }
+
+  #[test]
+  fn test_exact_accumulator_order() {
+    let mut random = Random(0x2545_F491_4F6C_DD1D);
+    let mut values: Vec<BID128> = (0..2_000).map(|_| d128(&format!("{}E{}", random.below(u64::MAX), random.below(60) as i64 - 30))).collect();
+    values.extend(values.clone().iter().map(|x| bid128_negate(*x)));
+    values.push(d128("0.123"));
+    let mut flags = FB_CLEAR;
+    let forward: ExactAccumulator = values.iter().copied().collect();
+    assert_eq!(
+      "+123000000000000000000000000000E-30",
+      bid128_to_string(forward.finish(RM_NEAREST_EVEN, &mut flags), &mut flags)
+    );
+    let backward: ExactAccumulator = values.iter().rev().copied().collect();
+    assert_eq!(
+      "+123000000000000000000000000000E-30",
+      bid128_to_string(backward.finish(RM_NEAREST_EVEN, &mut flags), &mut flags)
+    );
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_exact_accumulator_many() {
+    let mut accumulator = ExactAccumulator::new();
+    for _ in 0..100_000 {
+      accumulator.add(d128("9999999999999999999999999999999999E6000"));
+    }
+    let mut flags = FB_CLEAR;
+    assert_eq!(
+      "+9999999999999999999999999999999999E+6005",
+      bid128_to_string(accumulator.finish(RM_NEAREST_EVEN, &mut flags), &mut FB_CLEAR.clone())
+    );
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_exact_accumulator_overflow() {
+    let values = ["9999999999999999999999999999999999E6111", "1E6111"];
+    let mut flags = FB_CLEAR;
+    assert_eq!("+Inf", sum(&values, RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_OVERFLOW | FB_INEXACT, flags);
+    assert_eq!("+9999999999999999999999999999999999E+6111", sum(&values, RM_TOWARD_ZERO, &mut flags));
+  }
+
+  #[test]
+  fn test_exact_accumulator_zero() {
+    let mut flags = FB_CLEAR;
+    assert_eq!("-0E-2", sum(&["-0", "-0.00"], RM_NEAREST_EVEN, &mut flags));
+    assert_eq!("+0E+0", sum(&["1", "-1"], RM_NEAREST_EVEN, &mut flags));
+    assert_eq!("-0E+0", sum(&["1", "-1"], RM_DOWNWARD, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_exact_accumulator_special() {
+    let mut flags = FB_CLEAR;
+    assert_eq!("-Inf", sum(&["1", "-Inf", "1E6111"], RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+    assert_eq!("+NaN", sum(&["1", "-Inf", "Inf"], RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_INVALID, flags);
+  }
+
+  #[test]
+  fn test_exact_accumulator_merge() {
+    let mut a: ExactAccumulator = ["1E40", "0.5"].iter().map(|s| d128(s)).collect();
+    let b: ExactAccumulator = ["-1E40", "0.25"].iter().map(|s| d128(s)).collect();
+    a.merge(&b);
+    let mut flags = FB_CLEAR;
+    assert_eq!("+75E-2", bid128_to_string(a.finish(RM_NEAREST_EVEN, &mut flags), &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  /// Sum of two values rounded once is the same as the result of addition.
+  #[test]
+  fn test_exact_accumulator_add() {
+    let mut random = Random(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..20_000 {
+      let (x, y) = (random.decimal(), random.decimal());
+      let accumulator: ExactAccumulator = [x, y].into_iter().collect();
+      for round in ROUNDING {
+        let mut expected_flags = FB_CLEAR;
+        let expected = bid128_add(x, y, round, &mut expected_flags);
+        let mut flags = FB_CLEAR;
+        let actual = accumulator.finish(round, &mut flags);
+        let context = format!("{:x?} + {:x?}, round = {round}", x.w, y.w);
+        assert_eq!(expected.w, actual.w, "{context}");
+        assert_eq!(expected_flags, flags, "{context}");
+      }
+    }
+  }
+}
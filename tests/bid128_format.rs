@@ -0,0 +1,214 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Formatting tests
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_format {
+  use dfp_number_sys::*;
+
+  fn d128(s: &str) -> BID128 {
+    let mut flags = FB_CLEAR;
+    let x = bid128_from_string(s, RM_NEAREST_EVEN, &mut flags);
+    assert_eq!(FB_CLEAR, flags);
+    x
+  }
+
+  fn options(notation: Notation, precision: Option<usize>, round: u32, trailing_zeros: bool) -> FormatOptions {
+    FormatOptions {
+      notation,
+      precision,
+      round,
+      trailing_zeros,
+    }
+  }
+
+  #[test]
+  fn test_display() {
+    assert_eq!("0", format!("{}", d128("0")));
+    assert_eq!("0", format!("{}", d128("0.000")));
+    assert_eq!("-0", format!("{}", d128("-0")));
+    assert_eq!("1.23", format!("{}", d128("1.2300")));
+    assert_eq!("-123.45", format!("{}", d128("-123.45")));
+    assert_eq!("1200", format!("{}", d128("12E2")));
+    assert_eq!("0.00012", format!("{}", d128("1.2E-4")));
+    assert_eq!("9999999999999999999999999999999999", format!("{}", d128("9999999999999999999999999999999999")));
+    assert_eq!("NaN", format!("{}", d128("NaN")));
+    assert_eq!("inf", format!("{}", d128("Inf")));
+    assert_eq!("-inf", format!("{}", d128("-Inf")));
+  }
+
+  #[test]
+  fn test_display_precision() {
+    assert_eq!("1.24", format!("{:.2}", d128("1.235")));
+    assert_eq!("1.22", format!("{:.2}", d128("1.225")));
+    assert_eq!("-1.22", format!("{:.2}", d128("-1.225")));
+    assert_eq!("1.50", format!("{:.2}", d128("1.5")));
+    assert_eq!("2", format!("{:.0}", d128("2.5")));
+    assert_eq!("3", format!("{:.0}", d128("3.4999")));
+    assert_eq!("10.0", format!("{:.1}", d128("9.96")));
+    assert_eq!("1200.000", format!("{:.3}", d128("12E2")));
+    assert_eq!("0.00", format!("{:.2}", d128("0.001")));
+    assert_eq!("-0.00", format!("{:.2}", d128("-0.001")));
+  }
+
+  #[test]
+  fn test_display_padding() {
+    assert_eq!("     12.50", format!("{:>10.2}", d128("12.5")));
+    assert_eq!("12.50     ", format!("{:<10.2}", d128("12.5")));
+    assert_eq!("**12.50***", format!("{:*^10.2}", d128("12.5")));
+    assert_eq!("+12.5", format!("{:+}", d128("12.5")));
+    assert_eq!("-0012.5", format!("{:07}", d128("-12.5")));
+    assert_eq!("+0012.5", format!("{:+07}", d128("12.5")));
+    assert_eq!("  NaN", format!("{:>5}", d128("NaN")));
+    assert_eq!(" -inf", format!("{:>5}", d128("-Inf")));
+  }
+
+  #[test]
+  fn test_lower_exp() {
+    assert_eq!("1.2345e3", format!("{:e}", d128("1234.5")));
+    assert_eq!("1e0", format!("{:e}", d128("1")));
+    assert_eq!("1e3", format!("{:e}", d128("1000")));
+    assert_eq!("-1.5e-7", format!("{:e}", d128("-0.00000015")));
+    assert_eq!("0e0", format!("{:e}", d128("0.00")));
+    assert_eq!("1.23e3", format!("{:.2e}", d128("1234.5")));
+    assert_eq!("1.00e4", format!("{:.2e}", d128("9999")));
+    assert_eq!("5.000e-1", format!("{:.3e}", d128("0.5")));
+    assert_eq!("1E6144", format!("{:E}", d128("1E6144")));
+  }
+
+  #[test]
+  fn test_fixed_rounding_modes() {
+    let x = d128("-2.345");
+    let f = |round: u32| bid128_format(x, options(Notation::Fixed, Some(2), round, false)).to_string();
+    assert_eq!("-2.34", f(RM_NEAREST_EVEN));
+    assert_eq!("-2.35", f(RM_NEAREST_AWAY));
+    assert_eq!("-2.35", f(RM_DOWNWARD));
+    assert_eq!("-2.34", f(RM_UPWARD));
+    assert_eq!("-2.34", f(RM_TOWARD_ZERO));
+    assert_eq!("0.01", bid128_format(d128("1E-100"), options(Notation::Fixed, Some(2), RM_UPWARD, false)).to_string());
+    assert_eq!("0.00", bid128_format(d128("1E-100"), options(Notation::Fixed, Some(2), RM_DOWNWARD, false)).to_string());
+  }
+
+  #[test]
+  fn test_trailing_zeros() {
+    let fixed = options(Notation::Fixed, None, RM_NEAREST_EVEN, true);
+    assert_eq!("1.2300", bid128_format(d128("1.2300"), fixed).to_string());
+    assert_eq!("0.000", bid128_format(d128("0.000"), fixed).to_string());
+    assert_eq!("1200", bid128_format(d128("12E2"), fixed).to_string());
+    let scientific = options(Notation::Scientific, None, RM_NEAREST_EVEN, true);
+    assert_eq!("1.2300e0", bid128_format(d128("1.2300"), scientific).to_string());
+    assert_eq!("0e-3", bid128_format(d128("0.000"), scientific).to_string());
+  }
+
+  #[test]
+  fn test_engineering() {
+    let engineering = options(Notation::Engineering, None, RM_NEAREST_EVEN, false);
+    let f = |s: &str| bid128_format(d128(s), engineering).to_string();
+    assert_eq!("1e0", f("1"));
+    assert_eq!("12.3e0", f("12.3"));
+    assert_eq!("123.45e3", f("123450"));
+    assert_eq!("1.2345e3", f("1234.5"));
+    assert_eq!("12e-6", f("0.000012"));
+    assert_eq!("-100e-9", f("-1E-7"));
+    assert_eq!("0e0", f("0.000"));
+    let zeros = options(Notation::Engineering, None, RM_NEAREST_EVEN, true);
+    assert_eq!("0.00e-3", bid128_format(d128("0E-5"), zeros).to_string());
+    let precision = options(Notation::Engineering, Some(2), RM_NEAREST_EVEN, false);
+    assert_eq!("123.46e3", bid128_format(d128("123456"), precision).to_string());
+    assert_eq!("1.00e3", bid128_format(d128("999.999"), precision).to_string());
+    assert_eq!("12.00e-6", bid128_format(d128("0.000012"), precision).to_string());
+    assert_eq!("   1.00e3", format!("{:>9}", bid128_format(d128("999.999"), precision)));
+    assert_eq!("1.0e3", format!("{:.1}", bid128_format(d128("999.999"), precision)));
+  }
+
+  #[test]
+  fn test_locale_grouping() {
+    let en = Locale::new('.', ',', &[3], NegativeStyle::Minus);
+    let de = Locale::new(',', '.', &[3], NegativeStyle::Minus);
+    let ch = Locale::new('.', '\'', &[3], NegativeStyle::Minus);
+    let fr = Locale::new(',', '\u{202F}', &[3], NegativeStyle::Minus);
+    let fixed = FormatOptions::default();
+    assert_eq!("1,234,567.89", bid128_format_locale(d128("1234567.89"), fixed, &en));
+    assert_eq!("1.234.567,89", bid128_format_locale(d128("1234567.89"), fixed, &de));
+    assert_eq!("1'234'567.89", bid128_format_locale(d128("1234567.89"), fixed, &ch));
+    assert_eq!("1\u{202F}234\u{202F}567,89", bid128_format_locale(d128("1234567.89"), fixed, &fr));
+    assert_eq!("123", bid128_format_locale(d128("123"), fixed, &en));
+    assert_eq!("1,000", bid128_format_locale(d128("1000"), fixed, &en));
+    assert_eq!("0,5", bid128_format_locale(d128("0.5"), fixed, &de));
+    assert_eq!(
+      "1234567,89",
+      bid128_format_locale(d128("1234567.89"), fixed, &Locale::new(',', '.', &[], NegativeStyle::Minus))
+    );
+    assert_eq!(
+      "9,999,999,999,999,999,999,999,999,999,999,999",
+      bid128_format_locale(d128("9999999999999999999999999999999999"), fixed, &en)
+    );
+  }
+
+  #[test]
+  fn test_locale_indian_grouping() {
+    let india = Locale::new('.', ',', &[3, 2], NegativeStyle::Minus);
+    let fixed = FormatOptions::default();
+    assert_eq!("1,23,45,678.9", bid128_format_locale(d128("12345678.9"), fixed, &india));
+    assert_eq!("12,345", bid128_format_locale(d128("12345"), fixed, &india));
+    assert_eq!("999", bid128_format_locale(d128("999"), fixed, &india));
+    let precision = FormatOptions {
+      precision: Some(2),
+      ..Default::default()
+    };
+    assert_eq!("10,00,000.00", bid128_format_locale(d128("999999.999"), precision, &india));
+  }
+
+  #[test]
+  fn test_locale_negative_styles() {
+    let fixed = FormatOptions::default();
+    let minus = Locale::new(',', '.', &[3], NegativeStyle::Minus);
+    let parentheses = Locale::new(',', '.', &[3], NegativeStyle::Parentheses);
+    let trailing = Locale::new(',', '.', &[3], NegativeStyle::TrailingMinus);
+    assert_eq!("-1.234,5", bid128_format_locale(d128("-1234.5"), fixed, &minus));
+    assert_eq!("(1.234,5)", bid128_format_locale(d128("-1234.5"), fixed, &parentheses));
+    assert_eq!("1.234,5-", bid128_format_locale(d128("-1234.5"), fixed, &trailing));
+    assert_eq!("1.234,5", bid128_format_locale(d128("1234.5"), fixed, &parentheses));
+    assert_eq!("(inf)", bid128_format_locale(d128("-Inf"), fixed, &parentheses));
+    assert_eq!("NaN", bid128_format_locale(d128("-NaN"), fixed, &parentheses));
+  }
+
+  #[test]
+  fn test_locale_scientific() {
+    let de = Locale::new(',', '.', &[3], NegativeStyle::Minus);
+    let scientific = FormatOptions {
+      notation: Notation::Scientific,
+      ..Default::default()
+    };
+    assert_eq!("1,2345e3", bid128_format_locale(d128("1234.5"), scientific, &de));
+    assert_eq!("-1e-7", bid128_format_locale(d128("-1E-7"), scientific, &de));
+  }
+}
@@ -0,0 +1,197 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Parsing tests
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_parse {
+  use dfp_number_sys::*;
+
+  fn eq(expected: &str, actual: BID128) {
+    let mut flags: u32 = 0;
+    assert_eq!(expected, bid128_to_string(actual, &mut flags));
+    assert_eq!(0, flags);
+  }
+
+  fn strict(s: &str) -> Result<BID128, ParseError> {
+    bid128_parse(s, ParseMode::Strict, RM_NEAREST_EVEN, &mut FB_CLEAR.clone())
+  }
+
+  fn lenient(s: &str) -> Result<BID128, ParseError> {
+    bid128_parse(s, ParseMode::Lenient, RM_NEAREST_EVEN, &mut FB_CLEAR.clone())
+  }
+
+  #[test]
+  fn test_parse_strict() {
+    eq("-12345E-2", strict("-123.45").unwrap());
+    eq("+12345E-2", strict("123.45").unwrap());
+    eq("+5E-1", strict(".5").unwrap());
+    eq("+5E+0", strict("5.").unwrap());
+    eq("+0E-3", strict("0.000").unwrap());
+    eq("+12E+5", strict("12e5").unwrap());
+    eq("+12E-5", strict("12E-0005").unwrap());
+    eq("+12E+5", strict("12E+5").unwrap());
+    eq("+9999999999999999999999999999999999E+0", strict("9999999999999999999999999999999999").unwrap());
+    eq("+NaN", strict("NaN").unwrap());
+    eq("+SNaN", strict("sNaN").unwrap());
+    eq("-Inf", strict("-Infinity").unwrap());
+    eq("+Inf", strict("inf").unwrap());
+  }
+
+  #[test]
+  fn test_parse_strict_errors() {
+    assert_eq!(ParseError::Empty, strict("").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(0, ' '), strict(" 1").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(1, ' '), strict("1 ").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(0, '+'), strict("+1").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(1, '_'), strict("1_000").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(2, '\0'), strict("12\u{0}45").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(3, '.'), strict("1.2.3").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(3, 'x'), strict("1.2x").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(2, '€'), strict("12€").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(3, 'z'), strict("-inz").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(3, 'x'), strict("nanx").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(0, 'x'), strict("x1").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(3, '+'), strict("1e-+5").unwrap_err());
+    assert_eq!(ParseError::MissingDigits(1), strict("-").unwrap_err());
+    assert_eq!(ParseError::MissingDigits(1), strict(".").unwrap_err());
+    assert_eq!(ParseError::MissingDigits(1), strict(".e5").unwrap_err());
+    assert_eq!(ParseError::MissingDigits(2), strict("1e").unwrap_err());
+    assert_eq!(ParseError::MissingDigits(3), strict("1e-").unwrap_err());
+    assert_eq!(ParseError::MissingDigits(2), strict("in").unwrap_err());
+    assert_eq!(ParseError::ExponentOverflow, strict("1E6145").unwrap_err());
+    assert_eq!(ParseError::ExponentOverflow, strict("1E99999999").unwrap_err());
+    assert_eq!(ParseError::Inexact, strict("12345678901234567890123456789012345").unwrap_err());
+    assert_eq!(ParseError::Inexact, strict("1E-6200").unwrap_err());
+  }
+
+  #[test]
+  fn test_parse_lenient() {
+    eq("+12345E-2", lenient("  +123.45\t\n").unwrap());
+    eq("+1000000E+0", lenient("1_000_000").unwrap());
+    eq("-1234567E-3", lenient("-1_234.567").unwrap());
+    eq("+1E+10", lenient("1e1_0").unwrap());
+    eq("+Inf", lenient(" +Inf ").unwrap());
+    eq("+1234567890123456789012345678901234E+1", lenient("12345678901234567890123456789012345").unwrap());
+    eq("+1000000000000000000000000000000000E-33", lenient(&format!("1.{}", "0".repeat(200))).unwrap());
+    assert_eq!(ParseError::Empty, lenient(" \t ").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(2, '_'), lenient("  _1").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(2, '_'), lenient("1._5").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(1, '_'), lenient("1_").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(1, '_'), lenient("1__2").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(1, '_'), lenient("1_.5").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(1, '_'), lenient("1_e5").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(3, '_'), lenient("1e5_").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(3, '_'), lenient("1e5__0").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(1, ' '), lenient("1 000").unwrap_err());
+    assert_eq!(ParseError::InvalidChar(1, '+'), lenient("++1").unwrap_err());
+    assert_eq!(ParseError::ExponentOverflow, lenient("99.9E6144").unwrap_err());
+  }
+
+  #[test]
+  fn test_parse_flags() {
+    let mut flags = FB_CLEAR;
+    let x = bid128_parse("12345678901234567890123456789012345", ParseMode::Lenient, RM_DOWNWARD, &mut flags).unwrap();
+    assert_eq!(FB_INEXACT, flags);
+    eq("+1234567890123456789012345678901234E+1", x);
+    let mut flags = FB_CLEAR;
+    assert!(bid128_parse("1E6145", ParseMode::Lenient, RM_DOWNWARD, &mut flags).is_err());
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_parse_error_display() {
+    assert_eq!("empty input", ParseError::Empty.to_string());
+    assert_eq!("invalid character 'x' at position 3", ParseError::InvalidChar(3, 'x').to_string());
+    assert_eq!("missing digits at position 2", ParseError::MissingDigits(2).to_string());
+    assert_eq!("exponent out of range", ParseError::ExponentOverflow.to_string());
+    assert_eq!("value can not be represented exactly", ParseError::Inexact.to_string());
+  }
+
+  #[test]
+  fn test_parse_prefix() {
+    let (x, consumed) = bid128_parse_prefix("123.45+A1").unwrap();
+    eq("+12345E-2", x);
+    assert_eq!(6, consumed);
+    let (x, consumed) = bid128_parse_prefix("  -1.5e3*2").unwrap();
+    eq("-15E+2", x);
+    assert_eq!(8, consumed);
+    let (x, consumed) = bid128_parse_prefix("2E").unwrap();
+    eq("+2E+0", x);
+    assert_eq!(1, consumed);
+    let (x, consumed) = bid128_parse_prefix("Infinity)").unwrap();
+    eq("+Inf", x);
+    assert_eq!(8, consumed);
+    let (x, consumed) = bid128_parse_prefix("-inf,").unwrap();
+    eq("-Inf", x);
+    assert_eq!(4, consumed);
+    let (x, consumed) = bid128_parse_prefix(".5)").unwrap();
+    eq("+5E-1", x);
+    assert_eq!(2, consumed);
+    let (_, consumed) = bid128_parse_prefix("1\u{0}2").unwrap();
+    assert_eq!(1, consumed);
+    assert!(bid128_parse_prefix("").is_none());
+    assert!(bid128_parse_prefix("   ").is_none());
+    assert!(bid128_parse_prefix("abc").is_none());
+    assert!(bid128_parse_prefix(".").is_none());
+    assert!(bid128_parse_prefix(" +x").is_none());
+  }
+
+  #[test]
+  fn test_parse_prefix_utf16() {
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+    let (x, consumed) = bid128_parse_prefix_utf16(&utf16("-12.50;")).unwrap();
+    eq("-1250E-2", x);
+    assert_eq!(6, consumed);
+    let (x, consumed) = bid128_parse_prefix_utf16(&utf16("\t\x0B 7e2€")).unwrap();
+    eq("+7E+2", x);
+    assert_eq!(6, consumed);
+    assert!(bid128_parse_prefix_utf16(&utf16("\u{a0}7")).is_none());
+    assert!(bid128_parse_prefix_utf16(&utf16("\u{3000}7")).is_none());
+    let (x, consumed) = bid128_parse_prefix_utf16(&utf16("1\u{1F600}")).unwrap();
+    eq("+1E+0", x);
+    assert_eq!(1, consumed);
+    assert!(bid128_parse_prefix_utf16(&[]).is_none());
+    assert!(bid128_parse_prefix_utf16(&utf16("€1")).is_none());
+    assert!(bid128_parse_prefix_utf16(&[0xD800, b'1' as u16]).is_none());
+  }
+
+  #[test]
+  fn test_parse_prefix_utf32() {
+    let utf32 = |s: &str| s.chars().map(|ch| ch as u32).collect::<Vec<u32>>();
+    let (x, consumed) = bid128_parse_prefix_utf32(&utf32(" nan|")).unwrap();
+    eq("+NaN", x);
+    assert_eq!(4, consumed);
+    let (x, consumed) = bid128_parse_prefix_utf32(&utf32("0.001\u{0}5")).unwrap();
+    eq("+1E-3", x);
+    assert_eq!(5, consumed);
+    assert!(bid128_parse_prefix_utf32(&[0x110000, b'1' as u32]).is_none());
+  }
+}
@@ -0,0 +1,112 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Tests of decomposition into parts
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_parts {
+  use dfp_number_sys::*;
+
+  fn d128(s: &str) -> BID128 {
+    let mut flags = FB_CLEAR;
+    bid128_from_string(s, RM_NEAREST_EVEN, &mut flags)
+  }
+
+  fn eq(expected: &str, actual: BID128) {
+    let mut flags = FB_CLEAR;
+    assert_eq!(expected, bid128_to_string(actual, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_bid128_to_parts() {
+    assert_eq!(Parts::Finite(false, 12345, -2), bid128_to_parts(d128("123.45")));
+    assert_eq!(Parts::Finite(true, 0, -3), bid128_to_parts(d128("-0.000")));
+    assert_eq!(
+      Parts::Finite(false, 9_999_999_999_999_999_999_999_999_999_999_999, 6111),
+      bid128_to_parts(d128("9999999999999999999999999999999999E6111"))
+    );
+    assert_eq!(Parts::Finite(false, 1, -6176), bid128_to_parts(d128("1E-6176")));
+    assert_eq!(Parts::Infinite(true), bid128_to_parts(d128("-Inf")));
+    assert_eq!(Parts::NaN(false, false, 0), bid128_to_parts(d128("NaN")));
+    assert_eq!(Parts::NaN(true, true, 0), bid128_to_parts(d128("-SNaN")));
+  }
+
+  #[test]
+  fn test_bid128_words() {
+    let x = d128("-1");
+    assert_eq!((0xB040_0000_0000_0000, 1), bid128_to_words(x));
+    assert_eq!(x.w, bid128_from_words(0xB040_0000_0000_0000, 1).w);
+  }
+
+  #[test]
+  fn test_bid128_to_parts_non_canonical() {
+    // coefficient greater than 10^34 - 1
+    let x = bid128_from_words(0x3041_FFFF_FFFF_FFFF, u64::MAX);
+    assert_eq!(Parts::Finite(false, 0, 0), bid128_to_parts(x));
+    // payload greater than 10^33 - 1
+    let x = bid128_from_words(0xFC00_3FFF_FFFF_FFFF, u64::MAX);
+    assert_eq!(Parts::NaN(true, false, 0), bid128_to_parts(x));
+  }
+
+  #[test]
+  fn test_bid128_from_parts() {
+    eq("+12345E-2", bid128_from_parts(Parts::Finite(false, 12345, -2)).unwrap());
+    eq("-0E+6111", bid128_from_parts(Parts::Finite(true, 0, 6111)).unwrap());
+    eq(
+      "+9999999999999999999999999999999999E-6176",
+      bid128_from_parts(Parts::Finite(false, 9_999_999_999_999_999_999_999_999_999_999_999, -6176)).unwrap(),
+    );
+    eq("-Inf", bid128_from_parts(Parts::Infinite(true)).unwrap());
+    eq("+NaN", bid128_from_parts(Parts::NaN(false, false, 0)).unwrap());
+    eq("-SNaN", bid128_from_parts(Parts::NaN(true, true, 0)).unwrap());
+    let x = bid128_from_parts(Parts::NaN(false, false, 123)).unwrap();
+    assert_eq!(Parts::NaN(false, false, 123), bid128_to_parts(x));
+  }
+
+  #[test]
+  fn test_bid128_from_parts_errors() {
+    assert_eq!(PartsError::CoefficientOutOfRange, bid128_from_parts(Parts::Finite(false, 10_u128.pow(34), 0)).unwrap_err());
+    assert_eq!(PartsError::ExponentOutOfRange, bid128_from_parts(Parts::Finite(false, 1, 6112)).unwrap_err());
+    assert_eq!(PartsError::ExponentOutOfRange, bid128_from_parts(Parts::Finite(false, 1, -6177)).unwrap_err());
+    assert_eq!(PartsError::CoefficientOutOfRange, bid128_from_parts(Parts::NaN(false, true, 10_u128.pow(33))).unwrap_err());
+    assert_eq!("coefficient out of range", PartsError::CoefficientOutOfRange.to_string());
+    assert_eq!("exponent out of range", PartsError::ExponentOutOfRange.to_string());
+  }
+
+  #[test]
+  fn test_bid128_parts_round_trip() {
+    for s in ["0", "-1.5", "1E-6176", "-9999999999999999999999999999999999E6111", "Inf", "-NaN", "SNaN"] {
+      let x = d128(s);
+      let y = bid128_from_parts(bid128_to_parts(x)).unwrap();
+      assert_eq!(x.w, y.w, "{s}");
+    }
+  }
+}
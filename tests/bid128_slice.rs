@@ -0,0 +1,151 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Tests of operations on slices
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_slice {
+  use dfp_number_sys::*;
+
+  fn d128(s: &str) -> BID128 {
+    let mut flags = FB_CLEAR;
+    bid128_from_string(s, RM_NEAREST_EVEN, &mut flags)
+  }
+
+  fn d128s(s: &[&str]) -> Vec<BID128> {
+    s.iter().map(|s| d128(s)).collect()
+  }
+
+  fn eq(expected: &[&str], actual: &[BID128]) {
+    let mut flags = FB_CLEAR;
+    let actual: Vec<String> = actual.iter().map(|x| bid128_to_string(*x, &mut flags)).collect();
+    assert_eq!(expected, actual);
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_bid128_add_slices() {
+    let x = d128s(&["1.5", "-2", "9999999999999999999999999999999999"]);
+    let y = d128s(&["0.25", "2", "0.5"]);
+    let mut out = vec![bid128_from_int32(0); 3];
+    let flags = bid128_add_slices(&x, &y, &mut out, RM_NEAREST_EVEN);
+    assert_eq!(FB_INEXACT, flags);
+    eq(&["+175E-2", "+0E+0", "+1000000000000000000000000000000000E+1"], &out);
+  }
+
+  #[test]
+  fn test_bid128_sub_slices() {
+    let x = d128s(&["1.5", "-2"]);
+    let y = d128s(&["0.25", "2"]);
+    let mut out = vec![bid128_from_int32(0); 2];
+    assert_eq!(FB_CLEAR, bid128_sub_slices(&x, &y, &mut out, RM_NEAREST_EVEN));
+    eq(&["+125E-2", "-4E+0"], &out);
+  }
+
+  #[test]
+  fn test_bid128_mul_slices() {
+    let x = d128s(&["1.5", "-2", "1E6000"]);
+    let y = d128s(&["0.25", "2", "1E6000"]);
+    let mut out = vec![bid128_from_int32(0); 3];
+    let flags = bid128_mul_slices(&x, &y, &mut out, RM_NEAREST_EVEN);
+    assert_eq!(FB_OVERFLOW | FB_INEXACT, flags);
+    eq(&["+375E-3", "-4E+0", "+Inf"], &out);
+  }
+
+  #[test]
+  fn test_bid128_mul_slices_large() {
+    let mut x: Vec<BID128> = (0..10_000).map(bid128_from_int32).collect();
+    let mut out = vec![bid128_from_int32(0); x.len()];
+    assert_eq!(FB_CLEAR, bid128_mul_slices(&x, &x, &mut out, RM_NEAREST_EVEN));
+    for (i, z) in out.iter().enumerate() {
+      assert_eq!(format!("+{}E+0", i * i), bid128_to_string(*z, &mut FB_CLEAR.clone()));
+    }
+    x[9_999] = d128("1E6000");
+    assert_eq!(FB_OVERFLOW | FB_INEXACT, bid128_mul_slices(&x, &x, &mut out, RM_NEAREST_EVEN));
+  }
+
+  #[test]
+  #[should_panic(expected = "slices have different lengths")]
+  fn test_bid128_add_slices_different_lengths() {
+    let x = d128s(&["1", "2"]);
+    let mut out = vec![bid128_from_int32(0); 1];
+    bid128_add_slices(&x, &x, &mut out, RM_NEAREST_EVEN);
+  }
+
+  #[test]
+  fn test_bid128_scale_by_pow10() {
+    let x = d128s(&["1.5", "-2"]);
+    let mut out = vec![bid128_from_int32(0); 2];
+    assert_eq!(FB_CLEAR, bid128_scale_by_pow10(&x, 3, &mut out, RM_NEAREST_EVEN));
+    eq(&["+15E+2", "-2E+3"], &out);
+  }
+
+  #[test]
+  fn test_bid128_compare_into_mask() {
+    let x = d128s(&["1", "2", "3", "NaN"]);
+    let y = d128s(&["2", "2", "2", "2"]);
+    let mut mask = vec![false; 4];
+    let cases = [
+      (Comparison::Equal, [false, true, false, false]),
+      (Comparison::NotEqual, [true, false, true, true]),
+      (Comparison::Less, [true, false, false, false]),
+      (Comparison::LessEqual, [true, true, false, false]),
+      (Comparison::Greater, [false, false, true, false]),
+      (Comparison::GreaterEqual, [false, true, true, false]),
+    ];
+    for (relation, expected) in cases {
+      assert_eq!(FB_CLEAR, bid128_compare_into_mask(&x, &y, relation, &mut mask));
+      assert_eq!(expected.as_slice(), mask, "{relation:?}");
+    }
+    let x = d128s(&["SNaN"]);
+    assert_eq!(FB_INVALID, bid128_compare_into_mask(&x, &x, Comparison::Equal, &mut mask[..1]));
+  }
+
+  #[test]
+  fn test_bid128_sum() {
+    let mut flags = FB_CLEAR;
+    eq(&["+0E+0"], &[bid128_sum(&[], RM_NEAREST_EVEN, &mut flags)]);
+    eq(&["+1E+5"], &[bid128_sum(&d128s(&["1E5"]), RM_NEAREST_EVEN, &mut flags)]);
+    eq(&["+610E-2"], &[bid128_sum(&d128s(&["1.5", "2", "2.60"]), RM_NEAREST_EVEN, &mut flags)]);
+    assert_eq!(FB_CLEAR, flags);
+    let sum = bid128_sum(&d128s(&["9999999999999999999999999999999999", "0.5", "0.5"]), RM_NEAREST_EVEN, &mut flags);
+    assert_eq!(FB_INEXACT, flags);
+    eq(&["+1000000000000000000000000000000000E+1"], &[sum]);
+  }
+
+  #[test]
+  fn test_bid128_dot() {
+    let mut flags = FB_CLEAR;
+    eq(&["+0E+0"], &[bid128_dot(&[], &[], RM_NEAREST_EVEN, &mut flags)]);
+    let dot = bid128_dot(&d128s(&["1.5", "2", "-3"]), &d128s(&["2", "0.25", "1"]), RM_NEAREST_EVEN, &mut flags);
+    assert_eq!(FB_CLEAR, flags);
+    eq(&["+50E-2"], &[dot]);
+  }
+}
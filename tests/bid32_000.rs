@@ -0,0 +1,139 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Sanity tests of 32-bit decimals
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid32",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_bid32 {
+  use dfp_number_sys::*;
+
+  macro_rules! f {
+    () => {
+      &mut FB_CLEAR.clone()
+    };
+  }
+
+  fn d32(s: &str) -> BID32 {
+    let mut flags = FB_CLEAR;
+    bid32_from_string(s, RM_NEAREST_EVEN, &mut flags)
+  }
+
+  fn eq(expected: &str, actual: BID32) {
+    let mut flags = FB_CLEAR;
+    assert_eq!(expected, bid32_to_string(actual, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_bid32_arithmetic() {
+    let mut flags = FB_CLEAR;
+    eq("+175E-2", bid32_add(d32("1.5"), d32("0.25"), RM_NEAREST_EVEN, &mut flags));
+    eq("+125E-2", bid32_sub(d32("1.5"), d32("0.25"), RM_NEAREST_EVEN, &mut flags));
+    eq("+375E-3", bid32_mul(d32("1.5"), d32("0.25"), RM_NEAREST_EVEN, &mut flags));
+    eq("+6E+0", bid32_div(d32("1.5"), d32("0.25"), RM_NEAREST_EVEN, &mut flags));
+    eq("+12E+0", bid32_sqrt(d32("144"), RM_NEAREST_EVEN, &mut flags));
+    eq("+150E-2", bid32_quantize(d32("1.5"), d32("0.01"), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+    eq("+Inf", bid32_div(d32("1"), d32("0"), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_ZERO_DIVIDE, flags);
+  }
+
+  #[test]
+  fn test_bid32_from_bytes() {
+    eq("+1250E-2", bid32_from_bytes(b"12.50", RM_NEAREST_EVEN, f!()));
+    eq("+NaN", bid32_from_bytes(b"abc", RM_NEAREST_EVEN, f!()));
+    eq("+NaN", bid32_from_bytes(b"1\x002", RM_NEAREST_EVEN, f!()));
+    let long = format!("{}1.0", "0".repeat(200));
+    eq("+10E-1", bid32_from_bytes(long.as_bytes(), RM_NEAREST_EVEN, f!()));
+    let mut flags = FB_CLEAR;
+    let long = format!("1.{}", "0".repeat(200));
+    eq("+NaN", bid32_from_bytes(long.as_bytes(), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_INVALID, flags);
+  }
+
+  #[test]
+  fn test_bid32_to_str_buf() {
+    let mut buf = [0_u8; BID32_STRING_LENGTH];
+    assert_eq!("-12345E-2", bid32_to_str_buf(d32("-123.45"), &mut buf, f!()));
+    assert_eq!("-9999999E-101", bid32_to_str_buf(d32("-9999999E-101"), &mut buf, f!()));
+    let mut s = String::from("total=");
+    bid32_write_to(d32("1.50"), &mut s, f!()).unwrap();
+    assert_eq!("total=+150E-2", s);
+  }
+
+  #[test]
+  #[cfg(feature = "transcendental")]
+  fn test_bid32_pow() {
+    let mut flags = FB_CLEAR;
+    eq("+8E+0", bid32_pow(d32("2"), d32("3"), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_bid32_compare() {
+    let mut flags = FB_CLEAR;
+    assert!(bid32_quiet_equal(d32("1.50"), d32("1.5"), &mut flags));
+    assert!(bid32_quiet_less(d32("-1"), d32("1"), &mut flags));
+    assert!(!bid32_quiet_less(d32("NaN"), d32("1"), &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_bid32_from_int() {
+    let x = -25;
+    eq("-25E+0", bid32_from_int32(x, RM_NEAREST_EVEN, f!()));
+    eq("+12345E+0", bid32_from_int64(12345, RM_NEAREST_EVEN, f!()));
+  }
+
+  #[test]
+  fn test_bid32_from_string() {
+    let mut flags = FB_CLEAR;
+    eq("+NaN", bid32_from_string("1\u{0}2", RM_NEAREST_EVEN, &mut flags));
+    eq("+1234568E-6", bid32_from_string("1.23456789", RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_INEXACT, flags);
+  }
+
+  #[test]
+  fn test_bid32_to_int() {
+    let mut flags = FB_CLEAR;
+    assert_eq!(-12, bid32_to_int32_int(d32("-12.9"), &mut flags));
+    assert_eq!(123456, bid32_to_int64_int(d32("123456.5"), &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  #[cfg(all(feature = "bid64", feature = "bid128"))]
+  fn test_bid32_to_bid64() {
+    let x = d32("-1.5");
+    assert_eq!("-15E-1", bid64_to_string(bid32_to_bid64(x, f!()), f!()));
+    assert_eq!("-15E-1", bid128_to_string(bid32_to_bid128(x, f!()), f!()));
+    eq("-15E-1", bid128_to_bid32(bid32_to_bid128(x, f!()), RM_NEAREST_EVEN, f!()));
+  }
+}
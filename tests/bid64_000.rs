@@ -0,0 +1,140 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Sanity tests of 64-bit decimals
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid64",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_bid64 {
+  use dfp_number_sys::*;
+
+  macro_rules! f {
+    () => {
+      &mut FB_CLEAR.clone()
+    };
+  }
+
+  fn d64(s: &str) -> BID64 {
+    let mut flags = FB_CLEAR;
+    bid64_from_string(s, RM_NEAREST_EVEN, &mut flags)
+  }
+
+  fn eq(expected: &str, actual: BID64) {
+    let mut flags = FB_CLEAR;
+    assert_eq!(expected, bid64_to_string(actual, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_bid64_arithmetic() {
+    let mut flags = FB_CLEAR;
+    eq("+175E-2", bid64_add(d64("1.5"), d64("0.25"), RM_NEAREST_EVEN, &mut flags));
+    eq("+125E-2", bid64_sub(d64("1.5"), d64("0.25"), RM_NEAREST_EVEN, &mut flags));
+    eq("+375E-3", bid64_mul(d64("1.5"), d64("0.25"), RM_NEAREST_EVEN, &mut flags));
+    eq("+6E+0", bid64_div(d64("1.5"), d64("0.25"), RM_NEAREST_EVEN, &mut flags));
+    eq("+12E+0", bid64_sqrt(d64("144"), RM_NEAREST_EVEN, &mut flags));
+    eq("+150E-2", bid64_quantize(d64("1.5"), d64("0.01"), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+    eq("+Inf", bid64_div(d64("1"), d64("0"), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_ZERO_DIVIDE, flags);
+  }
+
+  #[test]
+  fn test_bid64_from_bytes() {
+    eq("+1250E-2", bid64_from_bytes(b"12.50", RM_NEAREST_EVEN, f!()));
+    eq("+NaN", bid64_from_bytes(b"abc", RM_NEAREST_EVEN, f!()));
+    eq("+NaN", bid64_from_bytes(b"1\x002", RM_NEAREST_EVEN, f!()));
+    let long = format!("{}1.0", "0".repeat(200));
+    eq("+10E-1", bid64_from_bytes(long.as_bytes(), RM_NEAREST_EVEN, f!()));
+    let mut flags = FB_CLEAR;
+    let long = format!("1.{}", "0".repeat(200));
+    eq("+NaN", bid64_from_bytes(long.as_bytes(), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_INVALID, flags);
+  }
+
+  #[test]
+  fn test_bid64_to_str_buf() {
+    let mut buf = [0_u8; BID64_STRING_LENGTH];
+    assert_eq!("-12345E-2", bid64_to_str_buf(d64("-123.45"), &mut buf, f!()));
+    assert_eq!("-9999999999999999E-398", bid64_to_str_buf(d64("-9999999999999999E-398"), &mut buf, f!()));
+    let mut s = String::from("total=");
+    bid64_write_to(d64("1.50"), &mut s, f!()).unwrap();
+    assert_eq!("total=+150E-2", s);
+  }
+
+  #[test]
+  #[cfg(feature = "transcendental")]
+  fn test_bid64_pow() {
+    let mut flags = FB_CLEAR;
+    eq("+8E+0", bid64_pow(d64("2"), d64("3"), RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_bid64_compare() {
+    let mut flags = FB_CLEAR;
+    assert!(bid64_quiet_equal(d64("1.50"), d64("1.5"), &mut flags));
+    assert!(bid64_quiet_less(d64("-1"), d64("1"), &mut flags));
+    assert!(!bid64_quiet_less(d64("NaN"), d64("1"), &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_bid64_from_int() {
+    let x = -25;
+    eq("-25E+0", bid64_from_int32(x));
+    eq("+12345E+0", bid64_from_int64(12345, RM_NEAREST_EVEN, f!()));
+  }
+
+  #[test]
+  fn test_bid64_from_string() {
+    let mut flags = FB_CLEAR;
+    eq("+NaN", bid64_from_string("1\u{0}2", RM_NEAREST_EVEN, &mut flags));
+    eq("+1234567890123457E-15", bid64_from_string("1.23456789012345678", RM_NEAREST_EVEN, &mut flags));
+    assert_eq!(FB_INEXACT, flags);
+  }
+
+  #[test]
+  fn test_bid64_to_int() {
+    let mut flags = FB_CLEAR;
+    assert_eq!(-12, bid64_to_int32_int(d64("-12.9"), &mut flags));
+    assert_eq!(123456, bid64_to_int64_int(d64("123456.5"), &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  #[cfg(all(feature = "bid32", feature = "bid128"))]
+  fn test_bid64_to_bid32() {
+    let mut flags = FB_CLEAR;
+    let x = bid64_to_bid32(d64("1.234567891"), RM_NEAREST_EVEN, &mut flags);
+    assert_eq!(FB_INEXACT, flags);
+    assert_eq!("+1234568E-6", bid32_to_string(x, f!()));
+    eq("+1234567891E-9", bid128_to_bid64(bid64_to_bid128(d64("1.234567891"), f!()), RM_NEAREST_EVEN, f!()));
+  }
+}
@@ -0,0 +1,75 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Tests of conversions between BID and DPD encodings
+
+#[cfg(all(feature = "dpd", not(feature = "call-by-reference"), not(feature = "global-rounding"), not(feature = "global-exception-flags")))]
+mod tests_bid_dpd {
+  use dfp_number_sys::*;
+
+  #[test]
+  fn test_bid_to_dpd32() {
+    assert_eq!(0x22500001, bid_to_dpd32(BID32 { w: 0x32800001 }));
+    assert_eq!(0x225000A3, bid_to_dpd32(BID32 { w: 0x3280007B }));
+    assert_eq!(0xA2500001, bid_to_dpd32(BID32 { w: 0xB2800001 }));
+    assert_eq!(0x78000000, bid_to_dpd32(BID32 { w: 0x78000000 }));
+  }
+
+  #[test]
+  fn test_bid_dpd_to_bid32() {
+    assert_eq!(0x32800001, bid_dpd_to_bid32(0x22500001).w);
+    assert_eq!(0x3280007B, bid_dpd_to_bid32(0x225000A3).w);
+    for w in [0x00000000, 0x3280007B, 0x6CB8967F, 0x77F8967F, 0xB2800001] {
+      assert_eq!(w, bid_dpd_to_bid32(bid_to_dpd32(BID32 { w })).w);
+    }
+  }
+
+  #[test]
+  fn test_bid_to_dpd64() {
+    assert_eq!(0x2238000000000001, bid_to_dpd64(BID64 { w: 0x31C0000000000001 }));
+    assert_eq!(0x22380000000000A3, bid_to_dpd64(BID64 { w: 0x31C000000000007B }));
+  }
+
+  #[test]
+  fn test_bid_dpd_to_bid64() {
+    assert_eq!(0x31C0000000000001, bid_dpd_to_bid64(0x2238000000000001).w);
+    for w in [0x0000000000000000, 0x31C000000000007B, 0x6C7386F26FC0FFFF, 0x77FB86F26FC0FFFF] {
+      assert_eq!(w, bid_dpd_to_bid64(bid_to_dpd64(BID64 { w })).w);
+    }
+  }
+
+  #[test]
+  fn test_bid_to_dpd128() {
+    assert_eq!(0x2208_0000_0000_0000_0000_0000_0000_0001, bid_to_dpd128(bid128_from_words(0x3040000000000000, 1)));
+    assert_eq!(0x2208_0000_0000_0000_0000_0000_0000_00A3, bid_to_dpd128(bid128_from_words(0x3040000000000000, 0x7B)));
+  }
+
+  #[test]
+  fn test_bid_dpd_to_bid128() {
+    assert_eq!(bid128_from_words(0x3040000000000000, 1).w, bid_dpd_to_bid128(0x2208_0000_0000_0000_0000_0000_0000_0001).w);
+    for (hi, lo) in [(0, 0), (0x3040000000000000, 0x7B), (0x3041ED09BEAD87C0, 0x378D8E63FFFFFFFF), (0xB040000000000000, 1)] {
+      assert_eq!(bid128_from_words(hi, lo).w, bid_dpd_to_bid128(bid_to_dpd128(bid128_from_words(hi, lo))).w);
+    }
+  }
+}
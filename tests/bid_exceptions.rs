@@ -0,0 +1,101 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Tests of alternate exception handling
+
+#[cfg(all(
+  feature = "alternate-exception-handling",
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_bid_exceptions {
+  use dfp_number_sys::*;
+
+  fn d128(s: &str) -> BID128 {
+    let mut flags = FB_CLEAR;
+    bid128_from_string(s, RM_NEAREST_EVEN, &mut flags)
+  }
+
+  fn div(x: &str, y: &str, flags: &mut u32) -> Result<BID128, DecimalException> {
+    bid_trap(flags, |flags| bid128_div(d128(x), d128(y), RM_NEAREST_EVEN, flags))
+  }
+
+  #[test]
+  fn test_all_exceptions_masked_by_default() {
+    assert_eq!(EM_ALL, bid_get_exception_masks());
+    let mut flags = FB_CLEAR;
+    assert!(div("1", "0", &mut flags).is_ok());
+    assert!(div("1", "3", &mut flags).is_ok());
+    assert_eq!(FB_ZERO_DIVIDE | FB_INEXACT, flags);
+  }
+
+  #[test]
+  fn test_unmasked_exceptions() {
+    let previous = bid_set_exception_masks(EM_ALL & !FB_ZERO_DIVIDE & !FB_INVALID);
+    assert_eq!(EM_ALL, previous);
+    let mut flags = FB_CLEAR;
+    let exception = div("1", "0", &mut flags).unwrap_err();
+    assert_eq!(FB_ZERO_DIVIDE, exception.unmasked);
+    assert_eq!(FB_ZERO_DIVIDE, exception.raised);
+    assert_eq!(FB_CLEAR, flags);
+    assert_eq!(FB_INVALID, div("0", "0", &mut flags).unwrap_err().unmasked);
+    let mut buf = [0_u8; BID128_STRING_LENGTH];
+    let x = div("1", "3", &mut flags).unwrap();
+    assert_eq!("+3333333333333333333333333333333333E-34", bid128_to_str_buf(x, &mut buf, &mut FB_CLEAR.clone()));
+    assert_eq!(FB_INEXACT, flags);
+    bid_set_exception_masks(previous);
+    assert!(div("1", "0", &mut flags).is_ok());
+  }
+
+  #[test]
+  fn test_exception_masks_per_thread() {
+    bid_set_exception_masks(FB_CLEAR);
+    std::thread::spawn(|| {
+      assert_eq!(EM_ALL, bid_get_exception_masks());
+      assert!(div("1", "3", &mut FB_CLEAR.clone()).is_ok());
+    })
+    .join()
+    .unwrap();
+    assert_eq!(FB_INEXACT, div("1", "3", &mut FB_CLEAR.clone()).unwrap_err().unmasked);
+  }
+
+  #[test]
+  fn test_exception_display() {
+    let exception = DecimalException {
+      unmasked: FB_OVERFLOW | FB_INEXACT,
+      raised: FB_OVERFLOW | FB_INEXACT,
+    };
+    assert_eq!("decimal exception: overflow, inexact result", exception.to_string());
+  }
+
+  #[test]
+  #[should_panic(expected = "decimal exception: division by zero")]
+  fn test_trap_or_panic() {
+    bid_set_exception_masks(EM_ALL & !FB_ZERO_DIVIDE);
+    let mut flags = FB_CLEAR;
+    bid_trap_or_panic(&mut flags, |flags| bid128_div(d128("1"), d128("0"), RM_NEAREST_EVEN, flags));
+  }
+}
@@ -0,0 +1,143 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Tests of thread-local rounding mode and exception flags
+
+#[cfg(all(any(feature = "global-rounding", feature = "global-exception-flags"), not(feature = "call-by-reference")))]
+mod tests_bid_globals {
+  use dfp_number_sys::*;
+  use std::sync::{Arc, Barrier};
+  use std::thread;
+
+  const THREADS: usize = 8;
+  const ITERATIONS: usize = 10_000;
+
+  /// Runs `f` with the index of the thread in [THREADS] threads started at the same time.
+  fn run_concurrently(f: impl Fn(usize) + Send + Sync + 'static) {
+    let f = Arc::new(f);
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let handles: Vec<_> = (0..THREADS)
+      .map(|index| {
+        let (f, barrier) = (f.clone(), barrier.clone());
+        thread::spawn(move || {
+          barrier.wait();
+          f(index)
+        })
+      })
+      .collect();
+    for handle in handles {
+      handle.join().unwrap();
+    }
+  }
+
+  #[test]
+  #[cfg(feature = "global-rounding")]
+  fn test_rounding_per_thread() {
+    run_concurrently(|index| {
+      let round = (index % 5) as u32;
+      assert_eq!(RM_NEAREST_EVEN, bid_get_decimal_rounding_direction());
+      for _ in 0..ITERATIONS {
+        bid_set_decimal_rounding_direction(round);
+        thread::yield_now();
+        assert_eq!(round, bid_get_decimal_rounding_direction());
+      }
+    });
+    assert_eq!(RM_NEAREST_EVEN, bid_get_decimal_rounding_direction());
+  }
+
+  #[test]
+  #[cfg(feature = "global-exception-flags")]
+  fn test_flags_per_thread() {
+    let all = FB_INVALID | FB_ZERO_DIVIDE | FB_OVERFLOW | FB_UNDERFLOW | FB_INEXACT;
+    run_concurrently(move |index| {
+      let flag = [FB_INVALID, FB_ZERO_DIVIDE, FB_OVERFLOW, FB_UNDERFLOW, FB_INEXACT][index % 5];
+      assert_eq!(FB_CLEAR, bid_test_flags(all));
+      for _ in 0..ITERATIONS {
+        bid_signal_exception(flag);
+        thread::yield_now();
+        assert_eq!(flag, bid_test_flags(all));
+        bid_lower_flags(all);
+        assert_eq!(FB_CLEAR, bid_test_flags(all));
+      }
+    });
+  }
+
+  #[test]
+  #[cfg(feature = "global-exception-flags")]
+  fn test_save_restore_flags() {
+    let all = FB_INVALID | FB_ZERO_DIVIDE | FB_OVERFLOW | FB_UNDERFLOW | FB_INEXACT;
+    bid_signal_exception(FB_INVALID | FB_INEXACT);
+    let saved = bid_save_flags(all);
+    assert_eq!(FB_INVALID | FB_INEXACT, saved);
+    bid_lower_flags(all);
+    bid_signal_exception(FB_OVERFLOW);
+    bid_restore_flags(saved, FB_INVALID | FB_OVERFLOW);
+    assert_eq!(FB_INVALID, bid_test_flags(all));
+    bid_lower_flags(all);
+  }
+
+  #[cfg(all(feature = "bid64", feature = "global-rounding", feature = "global-exception-flags"))]
+  mod arithmetic {
+    use super::*;
+
+    // bindings of operations are not generated for builds with global rounding mode and flags
+    extern "C" {
+      fn __bid64_div(x: BID64, y: BID64) -> BID64;
+      fn __bid64_from_int32(x: i32) -> BID64;
+    }
+
+    fn bid64(coefficient: u64, exponent: i32) -> BID64 {
+      BID64 {
+        w: ((exponent + 398) as u64) << 53 | coefficient,
+      }
+    }
+
+    fn div(x: i32, y: i32) -> BID64 {
+      unsafe { __bid64_div(__bid64_from_int32(x), __bid64_from_int32(y)) }
+    }
+
+    #[test]
+    fn test_arithmetic_per_thread() {
+      run_concurrently(|index| {
+        let round = (index % 5) as u32;
+        let expected = match round {
+          RM_DOWNWARD | RM_TOWARD_ZERO => bid64(6_666_666_666_666_666, -16),
+          _ => bid64(6_666_666_666_666_667, -16),
+        };
+        let all = FB_INVALID | FB_ZERO_DIVIDE | FB_OVERFLOW | FB_UNDERFLOW | FB_INEXACT;
+        bid_set_decimal_rounding_direction(round);
+        for _ in 0..ITERATIONS {
+          if index % 2 == 0 {
+            assert_eq!(expected.w, div(2, 3).w);
+            assert_eq!(FB_INEXACT, bid_test_flags(all));
+          } else {
+            assert_eq!(bid64(2, 0).w, div(6, 3).w);
+            assert_eq!(FB_CLEAR, bid_test_flags(all));
+          }
+          bid_lower_flags(all);
+        }
+      });
+    }
+  }
+}
@@ -0,0 +1,408 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # General Decimal Arithmetic (decTest) suite
+//!
+//! Runs every `*.decTest` file found in `tests/data/dectest`, the files are taken unchanged
+//! from the General Decimal Arithmetic testcases by Mike Cowlishaw (<https://speleotrove.com/decimal>),
+//! with their copyright headers. Files named `dq*`
+//! are run against 128-bit decimals, files named `dd*` against 64-bit decimals.
+//! Test cases using operations not mapped to the bindings, rounding modes absent
+//! from IEEE 754, DPD encoded operands or a context different from the format
+//! are skipped, intentional deviations of the library are listed in [SKIP].
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid64",
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_dectest {
+  use dfp_number_sys::*;
+  use std::fs;
+  use std::path::Path;
+
+  macro_rules! f {
+    () => {
+      &mut FB_CLEAR.clone()
+    };
+  }
+
+  /// The library propagates the quiet NaN operand, IEEE 754 leaves the choice of the propagated NaN open.
+  const NAN_CHOICE: &str = "propagated NaN";
+  /// For equal operands the library returns one of them, decTest orders them by sign and exponent.
+  const EQUAL_OPERANDS: &str = "equal operands of min/max";
+  /// The remainder is exact for any quotient, decTest raises `Division_impossible` when the quotient exceeds the precision.
+  const EXACT_REMAINDER: &str = "remainder of a large quotient";
+  /// Scaling beyond the range of exponents overflows or underflows, decTest reports it as invalid operation.
+  const SCALEB_RANGE: &str = "scale out of range";
+
+  /// Identifiers of test cases where the library intentionally deviates from decTest, with the reason.
+  #[rustfmt::skip]
+  const SKIP: &[(&str, &str)] = &[
+    ("ddqua699", NAN_CHOICE),
+    ("dqfma2693", NAN_CHOICE),
+    ("dqfma2713", NAN_CHOICE),
+    ("dqmax034", EQUAL_OPERANDS),
+    ("dqmax036", EQUAL_OPERANDS),
+    ("dqmax037", EQUAL_OPERANDS),
+    ("dqmax038", EQUAL_OPERANDS),
+    ("dqmax042", EQUAL_OPERANDS),
+    ("dqmax045", EQUAL_OPERANDS),
+    ("dqmax050", EQUAL_OPERANDS),
+    ("dqmax051", EQUAL_OPERANDS),
+    ("dqmax052", EQUAL_OPERANDS),
+    ("dqmax053", EQUAL_OPERANDS),
+    ("dqmax061", EQUAL_OPERANDS),
+    ("dqmax064", EQUAL_OPERANDS),
+    ("dqmax282", EQUAL_OPERANDS),
+    ("dqmax283", EQUAL_OPERANDS),
+    ("dqmax403", EQUAL_OPERANDS),
+    ("dqmax405", EQUAL_OPERANDS),
+    ("dqmax406", EQUAL_OPERANDS),
+    ("dqmax417", EQUAL_OPERANDS),
+    ("dqmax418", EQUAL_OPERANDS),
+    ("dqmax420", EQUAL_OPERANDS),
+    ("dqmax433", EQUAL_OPERANDS),
+    ("dqmax435", EQUAL_OPERANDS),
+    ("dqmax436", EQUAL_OPERANDS),
+    ("dqmax438", EQUAL_OPERANDS),
+    ("dqmax439", EQUAL_OPERANDS),
+    ("dqmax440", EQUAL_OPERANDS),
+    ("dqmax441", EQUAL_OPERANDS),
+    ("dqmax442", EQUAL_OPERANDS),
+    ("dqmax443", EQUAL_OPERANDS),
+    ("dqmax444", EQUAL_OPERANDS),
+    ("dqmax445", EQUAL_OPERANDS),
+    ("dqmax447", EQUAL_OPERANDS),
+    ("dqmax448", EQUAL_OPERANDS),
+    ("dqmax450", EQUAL_OPERANDS),
+    ("dqmax460", EQUAL_OPERANDS),
+    ("dqmax461", EQUAL_OPERANDS),
+    ("dqmax466", EQUAL_OPERANDS),
+    ("dqmax467", EQUAL_OPERANDS),
+    ("dqmin031", EQUAL_OPERANDS),
+    ("dqmin032", EQUAL_OPERANDS),
+    ("dqmin033", EQUAL_OPERANDS),
+    ("dqmin039", EQUAL_OPERANDS),
+    ("dqmin040", EQUAL_OPERANDS),
+    ("dqmin043", EQUAL_OPERANDS),
+    ("dqmin046", EQUAL_OPERANDS),
+    ("dqmin048", EQUAL_OPERANDS),
+    ("dqmin051", EQUAL_OPERANDS),
+    ("dqmin282", EQUAL_OPERANDS),
+    ("dqmin283", EQUAL_OPERANDS),
+    ("dqmin403", EQUAL_OPERANDS),
+    ("dqmin405", EQUAL_OPERANDS),
+    ("dqmin406", EQUAL_OPERANDS),
+    ("dqmin408", EQUAL_OPERANDS),
+    ("dqmin409", EQUAL_OPERANDS),
+    ("dqmin410", EQUAL_OPERANDS),
+    ("dqmin411", EQUAL_OPERANDS),
+    ("dqmin412", EQUAL_OPERANDS),
+    ("dqmin413", EQUAL_OPERANDS),
+    ("dqmin414", EQUAL_OPERANDS),
+    ("dqmin415", EQUAL_OPERANDS),
+    ("dqmin417", EQUAL_OPERANDS),
+    ("dqmin418", EQUAL_OPERANDS),
+    ("dqmin420", EQUAL_OPERANDS),
+    ("dqmin433", EQUAL_OPERANDS),
+    ("dqmin435", EQUAL_OPERANDS),
+    ("dqmin436", EQUAL_OPERANDS),
+    ("dqmin447", EQUAL_OPERANDS),
+    ("dqmin448", EQUAL_OPERANDS),
+    ("dqmin450", EQUAL_OPERANDS),
+    ("dqmin460", EQUAL_OPERANDS),
+    ("dqmin461", EQUAL_OPERANDS),
+    ("dqqua699", NAN_CHOICE),
+    ("dqrmn421", EXACT_REMAINDER),
+    ("dqrmn772", EXACT_REMAINDER),
+    ("dqrmn773", EXACT_REMAINDER),
+    ("dqrmn1051", EXACT_REMAINDER),
+    ("dqrmn1052", EXACT_REMAINDER),
+    ("dqrmn1053", EXACT_REMAINDER),
+    ("dqrmn1054", EXACT_REMAINDER),
+    ("dqscb122", SCALEB_RANGE),
+    ("dqscb123", SCALEB_RANGE),
+    ("dqscb126", SCALEB_RANGE),
+    ("dqscb127", SCALEB_RANGE),
+  ];
+
+  /// Decimal format selected by the prefix of the file name.
+  #[derive(Clone, Copy, PartialEq)]
+  enum Format {
+    Dq,
+    Dd,
+  }
+
+  impl Format {
+    /// Returns the precision, maximum and minimum exponent of the format.
+    fn limits(self) -> (i32, i32, i32) {
+      match self {
+        Format::Dq => (34, 6144, -6143),
+        Format::Dd => (16, 384, -383),
+      }
+    }
+  }
+
+  /// Context set by the directives of a decTest file.
+  struct Context {
+    precision: i32,
+    max_exponent: i32,
+    min_exponent: i32,
+    clamp: bool,
+    rounding: Option<u32>,
+  }
+
+  /// Outcome of a single test case.
+  enum Outcome {
+    Passed,
+    Skipped,
+    Failed(String),
+  }
+
+  /// Splits a line into tokens, keeping quoted strings together and dropping comments.
+  fn tokenize(line: &str) -> Vec<String> {
+    let chars = line.chars().collect::<Vec<char>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+      let c = chars[i];
+      if c.is_whitespace() {
+        i += 1;
+      } else if c == '-' && chars.get(i + 1) == Some(&'-') {
+        break;
+      } else if c == '\'' || c == '"' {
+        let mut token = String::new();
+        i += 1;
+        while i < chars.len() {
+          if chars[i] == c {
+            if chars.get(i + 1) != Some(&c) {
+              break;
+            }
+            i += 1;
+          }
+          token.push(chars[i]);
+          i += 1;
+        }
+        tokens.push(token);
+        i += 1;
+      } else {
+        let start = i;
+        while i < chars.len() && !chars[i].is_whitespace() {
+          i += 1;
+        }
+        tokens.push(chars[start..i].iter().collect());
+      }
+    }
+    tokens
+  }
+
+  fn rounding(name: &str) -> Option<u32> {
+    match name.to_lowercase().as_str() {
+      "half_even" => Some(RM_NEAREST_EVEN),
+      "half_up" => Some(RM_NEAREST_AWAY),
+      "floor" => Some(RM_DOWNWARD),
+      "ceiling" => Some(RM_UPWARD),
+      "down" => Some(RM_TOWARD_ZERO),
+      _ => None,
+    }
+  }
+
+  /// Maps decTest conditions to exception flags, conditions without an IEEE 754 flag are ignored.
+  fn conditions(names: &[String]) -> u32 {
+    names.iter().fold(FB_CLEAR, |flags, name| {
+      flags
+        | match name.to_lowercase().as_str() {
+          "inexact" => FB_INEXACT,
+          "underflow" => FB_UNDERFLOW,
+          "overflow" => FB_OVERFLOW,
+          "division_by_zero" => FB_ZERO_DIVIDE,
+          "invalid_operation" | "division_impossible" | "division_undefined" | "conversion_syntax" | "invalid_context" => FB_INVALID,
+          _ => FB_CLEAR,
+        }
+    })
+  }
+
+  fn integral128(round: u32) -> fn(BID128, &mut u32) -> BID128 {
+    match round {
+      RM_DOWNWARD => bid128_round_integral_negative,
+      RM_UPWARD => bid128_round_integral_positive,
+      RM_TOWARD_ZERO => bid128_round_integral_zero,
+      RM_NEAREST_AWAY => bid128_round_integral_nearest_away,
+      _ => bid128_round_integral_nearest_even,
+    }
+  }
+
+  /// Executes the operation on 128-bit decimals, returns encodings of the actual and expected result.
+  fn execute128(operation: &str, operands: &[String], result: &str, round: u32, flags: &mut u32) -> Option<([u64; 2], [u64; 2])> {
+    let d = |s: &str| bid128_from_string(s, round, &mut FB_CLEAR.clone());
+    let x = |i: usize| d(&operands[i]);
+    let actual = match (operation, operands.len()) {
+      ("add", 2) => bid128_add(x(0), x(1), round, flags),
+      ("subtract", 2) => bid128_sub(x(0), x(1), round, flags),
+      ("multiply", 2) => bid128_mul(x(0), x(1), round, flags),
+      ("divide", 2) => bid128_div(x(0), x(1), round, flags),
+      ("fma", 3) => bid128_fma(x(0), x(1), x(2), round, flags),
+      ("squareroot", 1) => bid128_sqrt(x(0), round, flags),
+      ("quantize", 2) => bid128_quantize(x(0), x(1), round, flags),
+      ("remaindernear", 2) => bid128_rem(x(0), x(1), flags),
+      ("max", 2) => bid128_maxnum(x(0), x(1), flags),
+      ("min", 2) => bid128_minnum(x(0), x(1), flags),
+      ("copy", 1) => bid128_copy(x(0)),
+      ("copyabs", 1) => bid128_abs(x(0)),
+      ("copynegate", 1) => bid128_negate(x(0)),
+      ("tointegralx", 1) => bid128_round_integral_exact(x(0), round, flags),
+      ("tointegral", 1) => integral128(round)(x(0), flags),
+      ("scaleb", 2) => bid128_scalbn_round(x(0), operands[1].parse().ok()?, round, flags),
+      ("tosci", 1) | ("apply", 1) => bid128_from_string(&operands[0], round, flags),
+      _ => return None,
+    };
+    Some((actual.w, d(result).w))
+  }
+
+  /// Executes the operation on 64-bit decimals, returns encodings of the actual and expected result.
+  fn execute64(operation: &str, operands: &[String], result: &str, round: u32, flags: &mut u32) -> Option<(u64, u64)> {
+    let d = |s: &str| bid64_from_string(s, round, &mut FB_CLEAR.clone());
+    let x = |i: usize| d(&operands[i]);
+    let actual = match (operation, operands.len()) {
+      ("add", 2) => bid64_add(x(0), x(1), round, flags),
+      ("subtract", 2) => bid64_sub(x(0), x(1), round, flags),
+      ("multiply", 2) => bid64_mul(x(0), x(1), round, flags),
+      ("divide", 2) => bid64_div(x(0), x(1), round, flags),
+      ("squareroot", 1) => bid64_sqrt(x(0), round, flags),
+      ("quantize", 2) => bid64_quantize(x(0), x(1), round, flags),
+      ("tosci", 1) | ("apply", 1) => bid64_from_string(&operands[0], round, flags),
+      _ => return None,
+    };
+    Some((actual.w, d(result).w))
+  }
+
+  fn run_case(format: Format, context: &Context, tokens: &[String]) -> Outcome {
+    let id = tokens[0].as_str();
+    let Some(arrow) = tokens.iter().position(|t| t == "->") else {
+      return Outcome::Failed(format!("{id}: missing result"));
+    };
+    if arrow + 1 >= tokens.len() {
+      return Outcome::Failed(format!("{id}: missing result"));
+    }
+    let operation = tokens[1].to_lowercase();
+    let operands = &tokens[2..arrow];
+    let result = tokens[arrow + 1].as_str();
+    let (precision, max_exponent, min_exponent) = format.limits();
+    let Some(round) = context.rounding else {
+      return Outcome::Skipped;
+    };
+    if SKIP.iter().any(|(skipped, _)| *skipped == id)
+      || context.precision != precision
+      || context.max_exponent != max_exponent
+      || context.min_exponent != min_exponent
+      || !context.clamp
+      || result == "?"
+      || result.starts_with('#')
+      || operands.iter().any(|operand| operand.starts_with('#'))
+    {
+      return Outcome::Skipped;
+    }
+    let expected_flags = conditions(&tokens[arrow + 2..]);
+    let mut flags = FB_CLEAR;
+    let (equal, actual, expected) = match format {
+      Format::Dq => match execute128(&operation, operands, result, round, &mut flags) {
+        Some((actual, expected)) => (actual == expected, bid128_to_string(BID128 { w: actual }, f!()), result),
+        None => return Outcome::Skipped,
+      },
+      Format::Dd => match execute64(&operation, operands, result, round, &mut flags) {
+        Some((actual, expected)) => (actual == expected, bid64_to_string(BID64 { w: actual }, f!()), result),
+        None => return Outcome::Skipped,
+      },
+    };
+    if equal && flags == expected_flags {
+      Outcome::Passed
+    } else {
+      Outcome::Failed(format!(
+        "{id}: {operation} {operands:?}: expected {expected} status {expected_flags:02x}, actual {actual} status {flags:02x}"
+      ))
+    }
+  }
+
+  fn run_file(format: Format, content: &str) -> (usize, usize, Vec<String>) {
+    let (mut passed, mut skipped, mut failures) = (0, 0, vec![]);
+    let mut context = Context {
+      precision: 0,
+      max_exponent: 0,
+      min_exponent: 0,
+      clamp: false,
+      rounding: Some(RM_NEAREST_EVEN),
+    };
+    for line in content.lines() {
+      let tokens = tokenize(line);
+      if tokens.is_empty() {
+        continue;
+      }
+      if let Some(directive) = tokens[0].strip_suffix(':') {
+        let value = tokens.get(1).map(String::as_str).unwrap_or_default();
+        match directive.to_lowercase().as_str() {
+          "precision" => context.precision = value.parse().unwrap_or_default(),
+          "maxexponent" => context.max_exponent = value.parse().unwrap_or_default(),
+          "minexponent" => context.min_exponent = value.parse().unwrap_or_default(),
+          "clamp" => context.clamp = value == "1",
+          "rounding" => context.rounding = rounding(value),
+          _ => {}
+        }
+        continue;
+      }
+      match run_case(format, &context, &tokens) {
+        Outcome::Passed => passed += 1,
+        Outcome::Skipped => skipped += 1,
+        Outcome::Failed(reason) => failures.push(reason),
+      }
+    }
+    (passed, skipped, failures)
+  }
+
+  #[test]
+  fn test_dectest() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/dectest");
+    let mut paths = fs::read_dir(dir).unwrap().map(|entry| entry.unwrap().path()).collect::<Vec<_>>();
+    paths.sort();
+    let (mut passed, mut failures) = (0, vec![]);
+    for path in paths.iter().filter(|path| path.extension().is_some_and(|ext| ext == "decTest")) {
+      let name = path.file_name().unwrap().to_string_lossy().to_lowercase();
+      let format = if name.starts_with("dq") {
+        Format::Dq
+      } else if name.starts_with("dd") {
+        Format::Dd
+      } else {
+        continue;
+      };
+      let (p, _, f) = run_file(format, &fs::read_to_string(path).unwrap());
+      passed += p;
+      failures.extend(f);
+    }
+    assert!(passed > 0, "no decTest cases passed");
+    assert!(failures.is_empty(), "{} decTest cases failed:\n{}", failures.len(), failures.join("\n"));
+  }
+}
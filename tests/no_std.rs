@@ -0,0 +1,102 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+//! # Tests of APIs available without the standard library
+
+#[cfg(all(
+  feature = "bid128",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_no_std {
+  use core::fmt;
+  use dfp_number_sys::*;
+
+  /// Fixed-size text sink, like the ones used by firmware without an allocator.
+  struct Sink {
+    buf: [u8; 64],
+    len: usize,
+  }
+
+  impl fmt::Write for Sink {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+      let end = self.len + s.len();
+      if end > self.buf.len() {
+        return Err(fmt::Error);
+      }
+      self.buf[self.len..end].copy_from_slice(s.as_bytes());
+      self.len = end;
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_arithmetic_with_buffers() {
+    let mut flags = FB_CLEAR;
+    let x = bid128_from_bytes(b"12.50", RM_NEAREST_EVEN, &mut flags);
+    let y = bid128_from_bytes(b"0.125", RM_NEAREST_EVEN, &mut flags);
+    let mut buf = [0_u8; BID128_STRING_LENGTH];
+    assert_eq!("+12625E-3", bid128_to_str_buf(bid128_add(x, y, RM_NEAREST_EVEN, &mut flags), &mut buf, &mut flags));
+    assert_eq!("+10E+1", bid128_to_str_buf(bid128_div(x, y, RM_NEAREST_EVEN, &mut flags), &mut buf, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+
+  #[test]
+  fn test_long_bytes() {
+    let mut buf = [b'0'; 200];
+    buf[199] = b'1';
+    let mut flags = FB_CLEAR;
+    let mut text = [0_u8; BID128_STRING_LENGTH];
+    let x = bid128_from_bytes(&buf, RM_NEAREST_EVEN, &mut flags);
+    assert_eq!("+1E+0", bid128_to_str_buf(x, &mut text, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+    buf[0] = b'.';
+    let x = bid128_from_bytes(&buf, RM_NEAREST_EVEN, &mut flags);
+    assert_eq!("+NaN", bid128_to_str_buf(x, &mut text, &mut flags));
+    assert_eq!(FB_INVALID, flags);
+  }
+
+  #[test]
+  fn test_write_to_fixed_sink() {
+    let mut flags = FB_CLEAR;
+    let mut sink = Sink { buf: [0; 64], len: 0 };
+    bid128_write_to(bid128_from_int64(-42), &mut sink, &mut flags).unwrap();
+    assert_eq!(b"-42E+0", &sink.buf[..sink.len]);
+    let mut sink = Sink { buf: [0; 64], len: 60 };
+    assert!(bid128_write_to(bid128_from_int64(-42), &mut sink, &mut flags).is_err());
+  }
+
+  #[test]
+  #[cfg(all(feature = "bid32", feature = "bid64"))]
+  fn test_narrow_formats_with_buffers() {
+    let mut flags = FB_CLEAR;
+    let mut buf32 = [0_u8; BID32_STRING_LENGTH];
+    let mut buf64 = [0_u8; BID64_STRING_LENGTH];
+    let x = bid32_from_bytes(b"-1.5", RM_NEAREST_EVEN, &mut flags);
+    assert_eq!("-15E-1", bid32_to_str_buf(x, &mut buf32, &mut flags));
+    let x = bid64_from_bytes(b"2.25", RM_NEAREST_EVEN, &mut flags);
+    assert_eq!("+225E-2", bid64_to_str_buf(x, &mut buf64, &mut flags));
+    assert_eq!(FB_CLEAR, flags);
+  }
+}
@@ -0,0 +1,1082 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Intel `readtest` suite
+//!
+//! Runs the test vectors from `IntelRDFPMathLib20U2/TESTS/readtest.in`, the file is taken unchanged
+//! from the Intel(R) Decimal Floating-Point Math Library V2.0 Update 2 distribution. Each line is read
+//! like the `readtest` checker (`IntelRDFPMathLib20U2/TESTS/readtest.c`) does:
+//!
+//! ```text
+//! function rounding operand [operand [operand]] result status [options]
+//! ```
+//!
+//! Operands and results are either decimal strings or raw encodings in brackets,
+//! integers are decimal or bracketed hexadecimal, the status is the hexadecimal
+//! set of expected exception flags, and `--` starts a comment.
+//! Operands are converted with rounding to nearest, results with the rounding mode of the vector.
+//! Results are compared bit by bit, except transcendental functions compared with the tolerance
+//! of `readtest` in units of the last place and `minnum`/`maxnum` compared by value.
+//! Vectors of functions without bindings are skipped, the functions are listed in [SKIP].
+
+#[cfg(all(
+  feature = "std",
+  feature = "bid32",
+  feature = "bid64",
+  feature = "bid128",
+  feature = "transcendental",
+  feature = "dpd",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_readtest {
+  use dfp_number_sys::*;
+  use std::collections::HashSet;
+  use std::fs;
+  use std::path::Path;
+
+  /// The function has no binding in this crate.
+  const NO_BINDING: &str = "no binding";
+  /// The binding is compiled only with the global rounding mode or global exception flags.
+  const GLOBAL_STATE: &str = "global rounding mode or exception flags";
+
+  /// Functions tested in `readtest.in` whose vectors are skipped, with the reason.
+  #[rustfmt::skip]
+  const SKIP: &[(&str, &str)] = &[
+    ("bid128_acos", NO_BINDING),
+    ("bid128_acosh", NO_BINDING),
+    ("bid128_asin", NO_BINDING),
+    ("bid128_asinh", NO_BINDING),
+    ("bid128_atan", NO_BINDING),
+    ("bid128_atan2", NO_BINDING),
+    ("bid128_atanh", NO_BINDING),
+    ("bid128_cbrt", NO_BINDING),
+    ("bid128_class", NO_BINDING),
+    ("bid128_copySign", NO_BINDING),
+    ("bid128_cos", NO_BINDING),
+    ("bid128_cosh", NO_BINDING),
+    ("bid128_erf", NO_BINDING),
+    ("bid128_erfc", NO_BINDING),
+    ("bid128_exp10", NO_BINDING),
+    ("bid128_exp2", NO_BINDING),
+    ("bid128_expm1", NO_BINDING),
+    ("bid128_fdim", NO_BINDING),
+    ("bid128_fmod", NO_BINDING),
+    ("bid128_hypot", NO_BINDING),
+    ("bid128_isCanonical", NO_BINDING),
+    ("bid128_isNaN", NO_BINDING),
+    ("bid128_isNormal", NO_BINDING),
+    ("bid128_isSignaling", NO_BINDING),
+    ("bid128_isSubnormal", NO_BINDING),
+    ("bid128_ldexp", NO_BINDING),
+    ("bid128_lgamma", NO_BINDING),
+    ("bid128_llquantexp", NO_BINDING),
+    ("bid128_llrint", NO_BINDING),
+    ("bid128_llround", NO_BINDING),
+    ("bid128_log10", NO_BINDING),
+    ("bid128_log1p", NO_BINDING),
+    ("bid128_log2", NO_BINDING),
+    ("bid128_logb", NO_BINDING),
+    ("bid128_lrint", NO_BINDING),
+    ("bid128_lround", NO_BINDING),
+    ("bid128_maxnum_mag", NO_BINDING),
+    ("bid128_minnum_mag", NO_BINDING),
+    ("bid128_modf", NO_BINDING),
+    ("bid128_nan", NO_BINDING),
+    ("bid128_nearbyint", NO_BINDING),
+    ("bid128_nextafter", NO_BINDING),
+    ("bid128_nextdown", NO_BINDING),
+    ("bid128_nexttoward", NO_BINDING),
+    ("bid128_nextup", NO_BINDING),
+    ("bid128_quiet_greater_unordered", NO_BINDING),
+    ("bid128_quiet_less_unordered", NO_BINDING),
+    ("bid128_quiet_not_equal", NO_BINDING),
+    ("bid128_quiet_not_greater", NO_BINDING),
+    ("bid128_quiet_not_less", NO_BINDING),
+    ("bid128_quiet_ordered", NO_BINDING),
+    ("bid128_quiet_unordered", NO_BINDING),
+    ("bid128_radix", NO_BINDING),
+    ("bid128_sameQuantum", NO_BINDING),
+    ("bid128_scalbln", NO_BINDING),
+    ("bid128_signaling_greater", NO_BINDING),
+    ("bid128_signaling_greater_equal", NO_BINDING),
+    ("bid128_signaling_greater_unordered", NO_BINDING),
+    ("bid128_signaling_less", NO_BINDING),
+    ("bid128_signaling_less_equal", NO_BINDING),
+    ("bid128_signaling_less_unordered", NO_BINDING),
+    ("bid128_signaling_not_greater", NO_BINDING),
+    ("bid128_signaling_not_less", NO_BINDING),
+    ("bid128_sin", NO_BINDING),
+    ("bid128_sinh", NO_BINDING),
+    ("bid128_tan", NO_BINDING),
+    ("bid128_tanh", NO_BINDING),
+    ("bid128_tgamma", NO_BINDING),
+    ("bid128_to_binary128", NO_BINDING),
+    ("bid128_to_binary32", NO_BINDING),
+    ("bid128_to_binary64", NO_BINDING),
+    ("bid128_to_binary80", NO_BINDING),
+    ("bid128_to_int16_ceil", NO_BINDING),
+    ("bid128_to_int16_floor", NO_BINDING),
+    ("bid128_to_int16_int", NO_BINDING),
+    ("bid128_to_int16_rnint", NO_BINDING),
+    ("bid128_to_int16_rninta", NO_BINDING),
+    ("bid128_to_int16_xceil", NO_BINDING),
+    ("bid128_to_int16_xfloor", NO_BINDING),
+    ("bid128_to_int16_xint", NO_BINDING),
+    ("bid128_to_int16_xrnint", NO_BINDING),
+    ("bid128_to_int16_xrninta", NO_BINDING),
+    ("bid128_to_int32_ceil", NO_BINDING),
+    ("bid128_to_int32_floor", NO_BINDING),
+    ("bid128_to_int32_rnint", NO_BINDING),
+    ("bid128_to_int32_rninta", NO_BINDING),
+    ("bid128_to_int32_xceil", NO_BINDING),
+    ("bid128_to_int32_xfloor", NO_BINDING),
+    ("bid128_to_int32_xint", NO_BINDING),
+    ("bid128_to_int32_xrnint", NO_BINDING),
+    ("bid128_to_int32_xrninta", NO_BINDING),
+    ("bid128_to_int64_ceil", NO_BINDING),
+    ("bid128_to_int64_floor", NO_BINDING),
+    ("bid128_to_int64_rnint", NO_BINDING),
+    ("bid128_to_int64_rninta", NO_BINDING),
+    ("bid128_to_int64_xceil", NO_BINDING),
+    ("bid128_to_int64_xfloor", NO_BINDING),
+    ("bid128_to_int64_xint", NO_BINDING),
+    ("bid128_to_int64_xrnint", NO_BINDING),
+    ("bid128_to_int64_xrninta", NO_BINDING),
+    ("bid128_to_int8_ceil", NO_BINDING),
+    ("bid128_to_int8_floor", NO_BINDING),
+    ("bid128_to_int8_int", NO_BINDING),
+    ("bid128_to_int8_rnint", NO_BINDING),
+    ("bid128_to_int8_rninta", NO_BINDING),
+    ("bid128_to_int8_xceil", NO_BINDING),
+    ("bid128_to_int8_xfloor", NO_BINDING),
+    ("bid128_to_int8_xint", NO_BINDING),
+    ("bid128_to_int8_xrnint", NO_BINDING),
+    ("bid128_to_int8_xrninta", NO_BINDING),
+    ("bid128_to_uint16_ceil", NO_BINDING),
+    ("bid128_to_uint16_floor", NO_BINDING),
+    ("bid128_to_uint16_int", NO_BINDING),
+    ("bid128_to_uint16_rnint", NO_BINDING),
+    ("bid128_to_uint16_rninta", NO_BINDING),
+    ("bid128_to_uint16_xceil", NO_BINDING),
+    ("bid128_to_uint16_xfloor", NO_BINDING),
+    ("bid128_to_uint16_xint", NO_BINDING),
+    ("bid128_to_uint16_xrnint", NO_BINDING),
+    ("bid128_to_uint16_xrninta", NO_BINDING),
+    ("bid128_to_uint32_ceil", NO_BINDING),
+    ("bid128_to_uint32_floor", NO_BINDING),
+    ("bid128_to_uint32_rnint", NO_BINDING),
+    ("bid128_to_uint32_rninta", NO_BINDING),
+    ("bid128_to_uint32_xceil", NO_BINDING),
+    ("bid128_to_uint32_xfloor", NO_BINDING),
+    ("bid128_to_uint32_xint", NO_BINDING),
+    ("bid128_to_uint32_xrnint", NO_BINDING),
+    ("bid128_to_uint32_xrninta", NO_BINDING),
+    ("bid128_to_uint64_ceil", NO_BINDING),
+    ("bid128_to_uint64_floor", NO_BINDING),
+    ("bid128_to_uint64_rnint", NO_BINDING),
+    ("bid128_to_uint64_rninta", NO_BINDING),
+    ("bid128_to_uint64_xceil", NO_BINDING),
+    ("bid128_to_uint64_xfloor", NO_BINDING),
+    ("bid128_to_uint64_xint", NO_BINDING),
+    ("bid128_to_uint64_xrnint", NO_BINDING),
+    ("bid128_to_uint64_xrninta", NO_BINDING),
+    ("bid128_to_uint8_ceil", NO_BINDING),
+    ("bid128_to_uint8_floor", NO_BINDING),
+    ("bid128_to_uint8_int", NO_BINDING),
+    ("bid128_to_uint8_rnint", NO_BINDING),
+    ("bid128_to_uint8_rninta", NO_BINDING),
+    ("bid128_to_uint8_xceil", NO_BINDING),
+    ("bid128_to_uint8_xfloor", NO_BINDING),
+    ("bid128_to_uint8_xint", NO_BINDING),
+    ("bid128_to_uint8_xrnint", NO_BINDING),
+    ("bid128_to_uint8_xrninta", NO_BINDING),
+    ("bid128_totalOrder", NO_BINDING),
+    ("bid128_totalOrderMag", NO_BINDING),
+    ("bid128d_sqrt", NO_BINDING),
+    ("bid128dd_add", NO_BINDING),
+    ("bid128dd_div", NO_BINDING),
+    ("bid128dd_mul", NO_BINDING),
+    ("bid128dd_sub", NO_BINDING),
+    ("bid128ddd_fma", NO_BINDING),
+    ("bid128ddq_fma", NO_BINDING),
+    ("bid128dq_add", NO_BINDING),
+    ("bid128dq_div", NO_BINDING),
+    ("bid128dq_mul", NO_BINDING),
+    ("bid128dq_sub", NO_BINDING),
+    ("bid128dqd_fma", NO_BINDING),
+    ("bid128dqq_fma", NO_BINDING),
+    ("bid128qd_add", NO_BINDING),
+    ("bid128qd_div", NO_BINDING),
+    ("bid128qd_mul", NO_BINDING),
+    ("bid128qd_sub", NO_BINDING),
+    ("bid128qdd_fma", NO_BINDING),
+    ("bid128qdq_fma", NO_BINDING),
+    ("bid128qqd_fma", NO_BINDING),
+    ("bid32_abs", NO_BINDING),
+    ("bid32_acos", NO_BINDING),
+    ("bid32_acosh", NO_BINDING),
+    ("bid32_asin", NO_BINDING),
+    ("bid32_asinh", NO_BINDING),
+    ("bid32_atan", NO_BINDING),
+    ("bid32_atan2", NO_BINDING),
+    ("bid32_atanh", NO_BINDING),
+    ("bid32_cbrt", NO_BINDING),
+    ("bid32_class", NO_BINDING),
+    ("bid32_copy", NO_BINDING),
+    ("bid32_copySign", NO_BINDING),
+    ("bid32_cos", NO_BINDING),
+    ("bid32_cosh", NO_BINDING),
+    ("bid32_erf", NO_BINDING),
+    ("bid32_erfc", NO_BINDING),
+    ("bid32_exp", NO_BINDING),
+    ("bid32_exp10", NO_BINDING),
+    ("bid32_exp2", NO_BINDING),
+    ("bid32_expm1", NO_BINDING),
+    ("bid32_fdim", NO_BINDING),
+    ("bid32_fma", NO_BINDING),
+    ("bid32_fmod", NO_BINDING),
+    ("bid32_frexp", NO_BINDING),
+    ("bid32_from_uint32", NO_BINDING),
+    ("bid32_from_uint64", NO_BINDING),
+    ("bid32_hypot", NO_BINDING),
+    ("bid32_ilogb", NO_BINDING),
+    ("bid32_inf", NO_BINDING),
+    ("bid32_isCanonical", NO_BINDING),
+    ("bid32_isFinite", NO_BINDING),
+    ("bid32_isInf", NO_BINDING),
+    ("bid32_isNaN", NO_BINDING),
+    ("bid32_isNormal", NO_BINDING),
+    ("bid32_isSignaling", NO_BINDING),
+    ("bid32_isSigned", NO_BINDING),
+    ("bid32_isSubnormal", NO_BINDING),
+    ("bid32_isZero", NO_BINDING),
+    ("bid32_ldexp", NO_BINDING),
+    ("bid32_lgamma", NO_BINDING),
+    ("bid32_llquantexp", NO_BINDING),
+    ("bid32_llrint", NO_BINDING),
+    ("bid32_llround", NO_BINDING),
+    ("bid32_log", NO_BINDING),
+    ("bid32_log10", NO_BINDING),
+    ("bid32_log1p", NO_BINDING),
+    ("bid32_log2", NO_BINDING),
+    ("bid32_logb", NO_BINDING),
+    ("bid32_lrint", NO_BINDING),
+    ("bid32_lround", NO_BINDING),
+    ("bid32_maxnum", NO_BINDING),
+    ("bid32_maxnum_mag", NO_BINDING),
+    ("bid32_minnum", NO_BINDING),
+    ("bid32_minnum_mag", NO_BINDING),
+    ("bid32_modf", NO_BINDING),
+    ("bid32_nan", NO_BINDING),
+    ("bid32_nearbyint", NO_BINDING),
+    ("bid32_negate", NO_BINDING),
+    ("bid32_nextafter", NO_BINDING),
+    ("bid32_nextdown", NO_BINDING),
+    ("bid32_nexttoward", NO_BINDING),
+    ("bid32_nextup", NO_BINDING),
+    ("bid32_quantexp", NO_BINDING),
+    ("bid32_quantum", NO_BINDING),
+    ("bid32_quiet_greater", NO_BINDING),
+    ("bid32_quiet_greater_equal", NO_BINDING),
+    ("bid32_quiet_greater_unordered", NO_BINDING),
+    ("bid32_quiet_less_equal", NO_BINDING),
+    ("bid32_quiet_less_unordered", NO_BINDING),
+    ("bid32_quiet_not_equal", NO_BINDING),
+    ("bid32_quiet_not_greater", NO_BINDING),
+    ("bid32_quiet_not_less", NO_BINDING),
+    ("bid32_quiet_ordered", NO_BINDING),
+    ("bid32_quiet_unordered", NO_BINDING),
+    ("bid32_radix", NO_BINDING),
+    ("bid32_rem", NO_BINDING),
+    ("bid32_round_integral_exact", NO_BINDING),
+    ("bid32_round_integral_nearest_away", NO_BINDING),
+    ("bid32_round_integral_nearest_even", NO_BINDING),
+    ("bid32_round_integral_negative", NO_BINDING),
+    ("bid32_round_integral_positive", NO_BINDING),
+    ("bid32_round_integral_zero", NO_BINDING),
+    ("bid32_sameQuantum", NO_BINDING),
+    ("bid32_scalbln", NO_BINDING),
+    ("bid32_scalbn", NO_BINDING),
+    ("bid32_signaling_greater", NO_BINDING),
+    ("bid32_signaling_greater_equal", NO_BINDING),
+    ("bid32_signaling_greater_unordered", NO_BINDING),
+    ("bid32_signaling_less", NO_BINDING),
+    ("bid32_signaling_less_equal", NO_BINDING),
+    ("bid32_signaling_less_unordered", NO_BINDING),
+    ("bid32_signaling_not_greater", NO_BINDING),
+    ("bid32_signaling_not_less", NO_BINDING),
+    ("bid32_sin", NO_BINDING),
+    ("bid32_sinh", NO_BINDING),
+    ("bid32_tan", NO_BINDING),
+    ("bid32_tanh", NO_BINDING),
+    ("bid32_tgamma", NO_BINDING),
+    ("bid32_to_binary128", NO_BINDING),
+    ("bid32_to_binary32", NO_BINDING),
+    ("bid32_to_binary64", NO_BINDING),
+    ("bid32_to_binary80", NO_BINDING),
+    ("bid32_to_int16_ceil", NO_BINDING),
+    ("bid32_to_int16_floor", NO_BINDING),
+    ("bid32_to_int16_int", NO_BINDING),
+    ("bid32_to_int16_rnint", NO_BINDING),
+    ("bid32_to_int16_rninta", NO_BINDING),
+    ("bid32_to_int16_xceil", NO_BINDING),
+    ("bid32_to_int16_xfloor", NO_BINDING),
+    ("bid32_to_int16_xint", NO_BINDING),
+    ("bid32_to_int16_xrnint", NO_BINDING),
+    ("bid32_to_int16_xrninta", NO_BINDING),
+    ("bid32_to_int32_ceil", NO_BINDING),
+    ("bid32_to_int32_floor", NO_BINDING),
+    ("bid32_to_int32_rnint", NO_BINDING),
+    ("bid32_to_int32_rninta", NO_BINDING),
+    ("bid32_to_int32_xceil", NO_BINDING),
+    ("bid32_to_int32_xfloor", NO_BINDING),
+    ("bid32_to_int32_xint", NO_BINDING),
+    ("bid32_to_int32_xrnint", NO_BINDING),
+    ("bid32_to_int32_xrninta", NO_BINDING),
+    ("bid32_to_int64_ceil", NO_BINDING),
+    ("bid32_to_int64_floor", NO_BINDING),
+    ("bid32_to_int64_rnint", NO_BINDING),
+    ("bid32_to_int64_rninta", NO_BINDING),
+    ("bid32_to_int64_xceil", NO_BINDING),
+    ("bid32_to_int64_xfloor", NO_BINDING),
+    ("bid32_to_int64_xint", NO_BINDING),
+    ("bid32_to_int64_xrnint", NO_BINDING),
+    ("bid32_to_int64_xrninta", NO_BINDING),
+    ("bid32_to_int8_ceil", NO_BINDING),
+    ("bid32_to_int8_floor", NO_BINDING),
+    ("bid32_to_int8_int", NO_BINDING),
+    ("bid32_to_int8_rnint", NO_BINDING),
+    ("bid32_to_int8_rninta", NO_BINDING),
+    ("bid32_to_int8_xceil", NO_BINDING),
+    ("bid32_to_int8_xfloor", NO_BINDING),
+    ("bid32_to_int8_xint", NO_BINDING),
+    ("bid32_to_int8_xrnint", NO_BINDING),
+    ("bid32_to_int8_xrninta", NO_BINDING),
+    ("bid32_to_uint16_ceil", NO_BINDING),
+    ("bid32_to_uint16_floor", NO_BINDING),
+    ("bid32_to_uint16_int", NO_BINDING),
+    ("bid32_to_uint16_rnint", NO_BINDING),
+    ("bid32_to_uint16_rninta", NO_BINDING),
+    ("bid32_to_uint16_xceil", NO_BINDING),
+    ("bid32_to_uint16_xfloor", NO_BINDING),
+    ("bid32_to_uint16_xint", NO_BINDING),
+    ("bid32_to_uint16_xrnint", NO_BINDING),
+    ("bid32_to_uint16_xrninta", NO_BINDING),
+    ("bid32_to_uint32_ceil", NO_BINDING),
+    ("bid32_to_uint32_floor", NO_BINDING),
+    ("bid32_to_uint32_int", NO_BINDING),
+    ("bid32_to_uint32_rnint", NO_BINDING),
+    ("bid32_to_uint32_rninta", NO_BINDING),
+    ("bid32_to_uint32_xceil", NO_BINDING),
+    ("bid32_to_uint32_xfloor", NO_BINDING),
+    ("bid32_to_uint32_xint", NO_BINDING),
+    ("bid32_to_uint32_xrnint", NO_BINDING),
+    ("bid32_to_uint32_xrninta", NO_BINDING),
+    ("bid32_to_uint64_ceil", NO_BINDING),
+    ("bid32_to_uint64_floor", NO_BINDING),
+    ("bid32_to_uint64_int", NO_BINDING),
+    ("bid32_to_uint64_rnint", NO_BINDING),
+    ("bid32_to_uint64_rninta", NO_BINDING),
+    ("bid32_to_uint64_xceil", NO_BINDING),
+    ("bid32_to_uint64_xfloor", NO_BINDING),
+    ("bid32_to_uint64_xint", NO_BINDING),
+    ("bid32_to_uint64_xrnint", NO_BINDING),
+    ("bid32_to_uint64_xrninta", NO_BINDING),
+    ("bid32_to_uint8_ceil", NO_BINDING),
+    ("bid32_to_uint8_floor", NO_BINDING),
+    ("bid32_to_uint8_int", NO_BINDING),
+    ("bid32_to_uint8_rnint", NO_BINDING),
+    ("bid32_to_uint8_rninta", NO_BINDING),
+    ("bid32_to_uint8_xceil", NO_BINDING),
+    ("bid32_to_uint8_xfloor", NO_BINDING),
+    ("bid32_to_uint8_xint", NO_BINDING),
+    ("bid32_to_uint8_xrnint", NO_BINDING),
+    ("bid32_to_uint8_xrninta", NO_BINDING),
+    ("bid32_totalOrder", NO_BINDING),
+    ("bid32_totalOrderMag", NO_BINDING),
+    ("bid64_abs", NO_BINDING),
+    ("bid64_acos", NO_BINDING),
+    ("bid64_acosh", NO_BINDING),
+    ("bid64_asin", NO_BINDING),
+    ("bid64_asinh", NO_BINDING),
+    ("bid64_atan", NO_BINDING),
+    ("bid64_atan2", NO_BINDING),
+    ("bid64_atanh", NO_BINDING),
+    ("bid64_cbrt", NO_BINDING),
+    ("bid64_class", NO_BINDING),
+    ("bid64_copy", NO_BINDING),
+    ("bid64_copySign", NO_BINDING),
+    ("bid64_cos", NO_BINDING),
+    ("bid64_cosh", NO_BINDING),
+    ("bid64_erf", NO_BINDING),
+    ("bid64_erfc", NO_BINDING),
+    ("bid64_exp", NO_BINDING),
+    ("bid64_exp10", NO_BINDING),
+    ("bid64_exp2", NO_BINDING),
+    ("bid64_expm1", NO_BINDING),
+    ("bid64_fdim", NO_BINDING),
+    ("bid64_fma", NO_BINDING),
+    ("bid64_fmod", NO_BINDING),
+    ("bid64_frexp", NO_BINDING),
+    ("bid64_from_uint32", NO_BINDING),
+    ("bid64_from_uint64", NO_BINDING),
+    ("bid64_hypot", NO_BINDING),
+    ("bid64_ilogb", NO_BINDING),
+    ("bid64_inf", NO_BINDING),
+    ("bid64_isCanonical", NO_BINDING),
+    ("bid64_isFinite", NO_BINDING),
+    ("bid64_isInf", NO_BINDING),
+    ("bid64_isNaN", NO_BINDING),
+    ("bid64_isNormal", NO_BINDING),
+    ("bid64_isSignaling", NO_BINDING),
+    ("bid64_isSigned", NO_BINDING),
+    ("bid64_isSubnormal", NO_BINDING),
+    ("bid64_isZero", NO_BINDING),
+    ("bid64_ldexp", NO_BINDING),
+    ("bid64_lgamma", NO_BINDING),
+    ("bid64_llquantexp", NO_BINDING),
+    ("bid64_llrint", NO_BINDING),
+    ("bid64_llround", NO_BINDING),
+    ("bid64_log", NO_BINDING),
+    ("bid64_log10", NO_BINDING),
+    ("bid64_log1p", NO_BINDING),
+    ("bid64_log2", NO_BINDING),
+    ("bid64_logb", NO_BINDING),
+    ("bid64_lrint", NO_BINDING),
+    ("bid64_lround", NO_BINDING),
+    ("bid64_maxnum", NO_BINDING),
+    ("bid64_maxnum_mag", NO_BINDING),
+    ("bid64_minnum", NO_BINDING),
+    ("bid64_minnum_mag", NO_BINDING),
+    ("bid64_modf", NO_BINDING),
+    ("bid64_nan", NO_BINDING),
+    ("bid64_nearbyint", NO_BINDING),
+    ("bid64_negate", NO_BINDING),
+    ("bid64_nextafter", NO_BINDING),
+    ("bid64_nextdown", NO_BINDING),
+    ("bid64_nexttoward", NO_BINDING),
+    ("bid64_nextup", NO_BINDING),
+    ("bid64_quantexp", NO_BINDING),
+    ("bid64_quantum", NO_BINDING),
+    ("bid64_quiet_greater", NO_BINDING),
+    ("bid64_quiet_greater_equal", NO_BINDING),
+    ("bid64_quiet_greater_unordered", NO_BINDING),
+    ("bid64_quiet_less_equal", NO_BINDING),
+    ("bid64_quiet_less_unordered", NO_BINDING),
+    ("bid64_quiet_not_equal", NO_BINDING),
+    ("bid64_quiet_not_greater", NO_BINDING),
+    ("bid64_quiet_not_less", NO_BINDING),
+    ("bid64_quiet_ordered", NO_BINDING),
+    ("bid64_quiet_unordered", NO_BINDING),
+    ("bid64_radix", NO_BINDING),
+    ("bid64_rem", NO_BINDING),
+    ("bid64_round_integral_exact", NO_BINDING),
+    ("bid64_round_integral_nearest_away", NO_BINDING),
+    ("bid64_round_integral_nearest_even", NO_BINDING),
+    ("bid64_round_integral_negative", NO_BINDING),
+    ("bid64_round_integral_positive", NO_BINDING),
+    ("bid64_round_integral_zero", NO_BINDING),
+    ("bid64_sameQuantum", NO_BINDING),
+    ("bid64_scalbln", NO_BINDING),
+    ("bid64_scalbn", NO_BINDING),
+    ("bid64_signaling_greater", NO_BINDING),
+    ("bid64_signaling_greater_equal", NO_BINDING),
+    ("bid64_signaling_greater_unordered", NO_BINDING),
+    ("bid64_signaling_less", NO_BINDING),
+    ("bid64_signaling_less_equal", NO_BINDING),
+    ("bid64_signaling_less_unordered", NO_BINDING),
+    ("bid64_signaling_not_greater", NO_BINDING),
+    ("bid64_signaling_not_less", NO_BINDING),
+    ("bid64_sin", NO_BINDING),
+    ("bid64_sinh", NO_BINDING),
+    ("bid64_tan", NO_BINDING),
+    ("bid64_tanh", NO_BINDING),
+    ("bid64_tgamma", NO_BINDING),
+    ("bid64_to_binary128", NO_BINDING),
+    ("bid64_to_binary32", NO_BINDING),
+    ("bid64_to_binary64", NO_BINDING),
+    ("bid64_to_binary80", NO_BINDING),
+    ("bid64_to_int16_ceil", NO_BINDING),
+    ("bid64_to_int16_floor", NO_BINDING),
+    ("bid64_to_int16_int", NO_BINDING),
+    ("bid64_to_int16_rnint", NO_BINDING),
+    ("bid64_to_int16_rninta", NO_BINDING),
+    ("bid64_to_int16_xceil", NO_BINDING),
+    ("bid64_to_int16_xfloor", NO_BINDING),
+    ("bid64_to_int16_xint", NO_BINDING),
+    ("bid64_to_int16_xrnint", NO_BINDING),
+    ("bid64_to_int16_xrninta", NO_BINDING),
+    ("bid64_to_int32_ceil", NO_BINDING),
+    ("bid64_to_int32_floor", NO_BINDING),
+    ("bid64_to_int32_rnint", NO_BINDING),
+    ("bid64_to_int32_rninta", NO_BINDING),
+    ("bid64_to_int32_xceil", NO_BINDING),
+    ("bid64_to_int32_xfloor", NO_BINDING),
+    ("bid64_to_int32_xint", NO_BINDING),
+    ("bid64_to_int32_xrnint", NO_BINDING),
+    ("bid64_to_int32_xrninta", NO_BINDING),
+    ("bid64_to_int64_ceil", NO_BINDING),
+    ("bid64_to_int64_floor", NO_BINDING),
+    ("bid64_to_int64_rnint", NO_BINDING),
+    ("bid64_to_int64_rninta", NO_BINDING),
+    ("bid64_to_int64_xceil", NO_BINDING),
+    ("bid64_to_int64_xfloor", NO_BINDING),
+    ("bid64_to_int64_xint", NO_BINDING),
+    ("bid64_to_int64_xrnint", NO_BINDING),
+    ("bid64_to_int64_xrninta", NO_BINDING),
+    ("bid64_to_int8_ceil", NO_BINDING),
+    ("bid64_to_int8_floor", NO_BINDING),
+    ("bid64_to_int8_int", NO_BINDING),
+    ("bid64_to_int8_rnint", NO_BINDING),
+    ("bid64_to_int8_rninta", NO_BINDING),
+    ("bid64_to_int8_xceil", NO_BINDING),
+    ("bid64_to_int8_xfloor", NO_BINDING),
+    ("bid64_to_int8_xint", NO_BINDING),
+    ("bid64_to_int8_xrnint", NO_BINDING),
+    ("bid64_to_int8_xrninta", NO_BINDING),
+    ("bid64_to_uint16_ceil", NO_BINDING),
+    ("bid64_to_uint16_floor", NO_BINDING),
+    ("bid64_to_uint16_int", NO_BINDING),
+    ("bid64_to_uint16_rnint", NO_BINDING),
+    ("bid64_to_uint16_rninta", NO_BINDING),
+    ("bid64_to_uint16_xceil", NO_BINDING),
+    ("bid64_to_uint16_xfloor", NO_BINDING),
+    ("bid64_to_uint16_xint", NO_BINDING),
+    ("bid64_to_uint16_xrnint", NO_BINDING),
+    ("bid64_to_uint16_xrninta", NO_BINDING),
+    ("bid64_to_uint32_ceil", NO_BINDING),
+    ("bid64_to_uint32_floor", NO_BINDING),
+    ("bid64_to_uint32_int", NO_BINDING),
+    ("bid64_to_uint32_rnint", NO_BINDING),
+    ("bid64_to_uint32_rninta", NO_BINDING),
+    ("bid64_to_uint32_xceil", NO_BINDING),
+    ("bid64_to_uint32_xfloor", NO_BINDING),
+    ("bid64_to_uint32_xint", NO_BINDING),
+    ("bid64_to_uint32_xrnint", NO_BINDING),
+    ("bid64_to_uint32_xrninta", NO_BINDING),
+    ("bid64_to_uint64_ceil", NO_BINDING),
+    ("bid64_to_uint64_floor", NO_BINDING),
+    ("bid64_to_uint64_int", NO_BINDING),
+    ("bid64_to_uint64_rnint", NO_BINDING),
+    ("bid64_to_uint64_rninta", NO_BINDING),
+    ("bid64_to_uint64_xceil", NO_BINDING),
+    ("bid64_to_uint64_xfloor", NO_BINDING),
+    ("bid64_to_uint64_xint", NO_BINDING),
+    ("bid64_to_uint64_xrnint", NO_BINDING),
+    ("bid64_to_uint64_xrninta", NO_BINDING),
+    ("bid64_to_uint8_ceil", NO_BINDING),
+    ("bid64_to_uint8_floor", NO_BINDING),
+    ("bid64_to_uint8_int", NO_BINDING),
+    ("bid64_to_uint8_rnint", NO_BINDING),
+    ("bid64_to_uint8_rninta", NO_BINDING),
+    ("bid64_to_uint8_xceil", NO_BINDING),
+    ("bid64_to_uint8_xfloor", NO_BINDING),
+    ("bid64_to_uint8_xint", NO_BINDING),
+    ("bid64_to_uint8_xrnint", NO_BINDING),
+    ("bid64_to_uint8_xrninta", NO_BINDING),
+    ("bid64_totalOrder", NO_BINDING),
+    ("bid64_totalOrderMag", NO_BINDING),
+    ("bid64ddq_fma", NO_BINDING),
+    ("bid64dq_add", NO_BINDING),
+    ("bid64dq_div", NO_BINDING),
+    ("bid64dq_mul", NO_BINDING),
+    ("bid64dq_sub", NO_BINDING),
+    ("bid64dqd_fma", NO_BINDING),
+    ("bid64dqq_fma", NO_BINDING),
+    ("bid64q_sqrt", NO_BINDING),
+    ("bid64qd_add", NO_BINDING),
+    ("bid64qd_div", NO_BINDING),
+    ("bid64qd_mul", NO_BINDING),
+    ("bid64qd_sub", NO_BINDING),
+    ("bid64qdd_fma", NO_BINDING),
+    ("bid64qdq_fma", NO_BINDING),
+    ("bid64qq_add", NO_BINDING),
+    ("bid64qq_div", NO_BINDING),
+    ("bid64qq_mul", NO_BINDING),
+    ("bid64qq_sub", NO_BINDING),
+    ("bid64qqd_fma", NO_BINDING),
+    ("bid64qqq_fma", NO_BINDING),
+    ("bid_feclearexcept", NO_BINDING),
+    ("bid_fegetexceptflag", NO_BINDING),
+    ("bid_feraiseexcept", NO_BINDING),
+    ("bid_fesetexceptflag", NO_BINDING),
+    ("bid_fetestexcept", NO_BINDING),
+    ("bid_getDecimalRoundingDirection", GLOBAL_STATE),
+    ("bid_is754", NO_BINDING),
+    ("bid_is754R", NO_BINDING),
+    ("bid_lowerFlags", GLOBAL_STATE),
+    ("bid_restoreFlags", GLOBAL_STATE),
+    ("bid_saveFlags", GLOBAL_STATE),
+    ("bid_setDecimalRoundingDirection", GLOBAL_STATE),
+    ("bid_signalException", GLOBAL_STATE),
+    ("bid_strtod32", NO_BINDING),
+    ("bid_strtod64", NO_BINDING),
+    ("bid_testFlags", GLOBAL_STATE),
+    ("bid_testSavedFlags", NO_BINDING),
+    ("bid_wcstod128", NO_BINDING),
+    ("bid_wcstod32", NO_BINDING),
+    ("bid_wcstod64", NO_BINDING),
+    ("binary128_to_bid128", NO_BINDING),
+    ("binary128_to_bid32", NO_BINDING),
+    ("binary128_to_bid64", NO_BINDING),
+    ("binary32_to_bid128", NO_BINDING),
+    ("binary32_to_bid32", NO_BINDING),
+    ("binary32_to_bid64", NO_BINDING),
+    ("binary64_to_bid128", NO_BINDING),
+    ("binary64_to_bid32", NO_BINDING),
+    ("binary64_to_bid64", NO_BINDING),
+    ("binary80_to_bid128", NO_BINDING),
+    ("binary80_to_bid32", NO_BINDING),
+    ("binary80_to_bid64", NO_BINDING),
+    ("str64", NO_BINDING),
+  ];
+
+  /// Number of vectors skipped because their functions are listed in [SKIP].
+  const SKIPPED: usize = 114_337;
+
+  /// Exception flags compared for transcendental functions (invalid and division by zero),
+  /// other flags depend on the accuracy of the result.
+  const TRANSCENDENTAL_FLAGS: u32 = 0x05;
+
+  /// Single test vector.
+  struct Vector<'a> {
+    line: usize,
+    function: &'a str,
+    round: u32,
+    operands: Vec<&'a str>,
+    result: &'a str,
+    status: u32,
+    /// Error of the expected result in units of the last place, set with the `ulp=` option.
+    ulp: f64,
+    /// Prefix of converted strings, set with the `str_prefix=|...|` option.
+    prefix: &'a str,
+  }
+
+  /// Value returned by the tested function.
+  #[derive(Debug, PartialEq)]
+  enum Value {
+    Dec128([u64; 2]),
+    Dec64(u64),
+    Dec32(u32),
+    Frexp([u64; 2], i32),
+    Int32(i32),
+    Uint32(u32),
+    Int64(i64),
+    Uint64(u64),
+    Str(String),
+  }
+
+  /// Comparison of the actual and expected results, selected like in `readtest.h`.
+  enum Comparison {
+    /// Results and exception flags must be equal.
+    Exact,
+    /// Results must be equal in value, exception flags must be equal.
+    Equal,
+    /// Results must be within the tolerance, only [TRANSCENDENTAL_FLAGS] are compared.
+    RelativeError,
+  }
+
+  fn comparison(function: &str) -> Comparison {
+    match function {
+      "bid128_exp" | "bid128_log" | "bid128_pow" | "bid64_pow" | "bid32_pow" => Comparison::RelativeError,
+      "bid128_maxnum" | "bid128_minnum" => Comparison::Equal,
+      _ => Comparison::Exact,
+    }
+  }
+
+  /// Returns the maximum error in units of the last place for each rounding mode, like `readtest`.
+  fn tolerance(function: &str) -> [f64; 5] {
+    if function.contains("128") {
+      [2.0, 5.0, 5.0, 5.0, 2.0]
+    } else if function.contains("64") {
+      [0.55, 1.05, 1.05, 1.05, 0.55]
+    } else {
+      [0.5, 1.01, 1.01, 1.01, 0.5]
+    }
+  }
+
+  /// Parses a single line, options `underflow_before_only` and `longintsize=` are ignored,
+  /// because the library detects tininess before rounding like `readtest` by default
+  /// and none of the bound functions returns C `long`.
+  fn parse_vector(line: usize, text: &str) -> Result<Option<Vector<'_>>, String> {
+    let text = text.split("--").next().unwrap_or_default();
+    let mut end = text.len();
+    let mut ulp = 0.0;
+    let mut prefix = "";
+    for option in ["ulp=", "underflow_before_only", "str_prefix=", "longintsize="] {
+      let Some(position) = text.find(option) else {
+        continue;
+      };
+      end = end.min(position);
+      let value = &text[position + option.len()..];
+      match option {
+        "ulp=" => ulp = value.split_whitespace().next().and_then(|s| s.parse().ok()).unwrap_or_default(),
+        "str_prefix=" => prefix = value.strip_prefix('|').and_then(|s| s.split('|').next()).unwrap_or_default(),
+        _ => {}
+      }
+    }
+    let tokens = text[..end].split_whitespace().collect::<Vec<&str>>();
+    if tokens.is_empty() {
+      return Ok(None);
+    }
+    // fields are matched like `sscanf` does in `readtest.c`, lines with three, two and one operand
+    // are tried in turn and tokens following the status are ignored
+    for count in [7, 6, 5] {
+      let (Some(round), Some(status)) = (tokens.get(1).and_then(|s| s.parse::<u32>().ok()), tokens.get(count - 1).and_then(|s| status(s))) else {
+        continue;
+      };
+      return Ok(Some(Vector {
+        line,
+        function: tokens[0],
+        round,
+        operands: tokens[2..count - 2].to_vec(),
+        result: tokens[count - 2],
+        status,
+        ulp,
+        prefix,
+      }));
+    }
+    Err(format!("line {line}: invalid fields"))
+  }
+
+  /// Parses the status like `%x` conversion, with optional `0x` prefix and trailing characters ignored.
+  fn status(s: &str) -> Option<u32> {
+    let s = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+    let digits = s.find(|c: char| !c.is_ascii_hexdigit()).unwrap_or(s.len());
+    u32::from_str_radix(&s[..digits], 16).ok()
+  }
+
+  fn hex(s: &str) -> Option<&str> {
+    s.strip_prefix('[').and_then(|s| s.strip_suffix(']'))
+  }
+
+  fn dec128(s: &str, round: u32) -> BID128 {
+    match hex(s) {
+      Some(h) => {
+        let (hi, lo) = h.split_once(',').unwrap_or_else(|| h.split_at(h.len().saturating_sub(16)));
+        bid128_from_words(u64::from_str_radix(hi, 16).unwrap(), u64::from_str_radix(lo, 16).unwrap())
+      }
+      None => bid128_from_string(s, round, &mut FB_CLEAR.clone()),
+    }
+  }
+
+  fn dec64(s: &str, round: u32) -> BID64 {
+    match hex(s) {
+      Some(h) => BID64 {
+        w: u64::from_str_radix(h, 16).unwrap(),
+      },
+      None => bid64_from_string(s, round, &mut FB_CLEAR.clone()),
+    }
+  }
+
+  fn dec32(s: &str, round: u32) -> BID32 {
+    match hex(s) {
+      Some(h) => BID32 {
+        w: u32::from_str_radix(h, 16).unwrap(),
+      },
+      None => bid32_from_string(s, round, &mut FB_CLEAR.clone()),
+    }
+  }
+
+  fn int(s: &str) -> i128 {
+    match hex(s) {
+      Some(h) => i128::from_str_radix(h, 16).unwrap(),
+      None => s.parse::<i128>().unwrap(),
+    }
+  }
+
+  /// Returns the DPD encoded operand, given like the BID encoded one.
+  fn dpd128(x: BID128) -> u128 {
+    let (hi, lo) = bid128_to_words(x);
+    (hi as u128) << 64 | lo as u128
+  }
+
+  /// Calls the function named in the vector, the names follow the C library.
+  /// Returns `None` for functions without bindings.
+  fn execute(v: &Vector, flags: &mut u32) -> Option<Value> {
+    use Value::*;
+    let rnd = v.round;
+    let op = |i: usize| v.operands.get(i).copied().unwrap_or_default();
+    let x128 = |i: usize| dec128(op(i), RM_NEAREST_EVEN);
+    let x64 = |i: usize| dec64(op(i), RM_NEAREST_EVEN);
+    let x32 = |i: usize| dec32(op(i), RM_NEAREST_EVEN);
+    let n = |i: usize| int(op(i));
+    Some(match v.function {
+      "bid128_abs" => Dec128(bid128_abs(x128(0)).w),
+      "bid128_add" => Dec128(bid128_add(x128(0), x128(1), rnd, flags).w),
+      "bid128_copy" => Dec128(bid128_copy(x128(0)).w),
+      "bid128_div" => Dec128(bid128_div(x128(0), x128(1), rnd, flags).w),
+      "bid128_exp" => Dec128(bid128_exp(x128(0), rnd, flags).w),
+      "bid128_fma" => Dec128(bid128_fma(x128(0), x128(1), x128(2), rnd, flags).w),
+      "bid128_frexp" => {
+        let mut exp = 0;
+        let x = bid128_frexp(x128(0), &mut exp);
+        Frexp(x.w, exp)
+      }
+      "bid128_from_int32" => Dec128(bid128_from_int32(n(0) as i32).w),
+      "bid128_from_int64" => Dec128(bid128_from_int64(n(0) as i64).w),
+      "bid128_from_string" => Dec128(bid128_from_string(op(0), rnd, flags).w),
+      "bid128_from_uint32" => Dec128(bid128_from_uint32(n(0) as u32).w),
+      "bid128_from_uint64" => Dec128(bid128_from_uint64(n(0) as u64).w),
+      "bid128_ilogb" => Int32(bid128_ilogb(x128(0), flags)),
+      "bid128_inf" => Dec128(bid128_inf().w),
+      "bid128_isFinite" => Int32(bid128_is_finite(x128(0)) as i32),
+      "bid128_isInf" => Int32(bid128_is_infinite(x128(0)) as i32),
+      "bid128_isSigned" => Int32(bid128_is_signed(x128(0)) as i32),
+      "bid128_isZero" => Int32(bid128_is_zero(x128(0)) as i32),
+      "bid128_log" => Dec128(bid128_log(x128(0), rnd, flags).w),
+      "bid128_maxnum" => Dec128(bid128_maxnum(x128(0), x128(1), flags).w),
+      "bid128_minnum" => Dec128(bid128_minnum(x128(0), x128(1), flags).w),
+      "bid128_mul" => Dec128(bid128_mul(x128(0), x128(1), rnd, flags).w),
+      "bid128_negate" => Dec128(bid128_negate(x128(0)).w),
+      "bid128_pow" => Dec128(bid128_pow(x128(0), x128(1), rnd, flags).w),
+      "bid128_quantexp" => Int32(bid128_quantexp(x128(0), flags)),
+      "bid128_quantize" => Dec128(bid128_quantize(x128(0), x128(1), rnd, flags).w),
+      "bid128_quantum" => Dec128(bid128_quantum(x128(0)).w),
+      "bid128_quiet_equal" => Int32(bid128_quiet_equal(x128(0), x128(1), flags) as i32),
+      "bid128_quiet_greater" => Int32(bid128_quiet_greater(x128(0), x128(1), flags) as i32),
+      "bid128_quiet_greater_equal" => Int32(bid128_quiet_greater_equal(x128(0), x128(1), flags) as i32),
+      "bid128_quiet_less" => Int32(bid128_quiet_less(x128(0), x128(1), flags) as i32),
+      "bid128_quiet_less_equal" => Int32(bid128_quiet_less_equal(x128(0), x128(1), flags) as i32),
+      "bid128_rem" => Dec128(bid128_rem(x128(0), x128(1), flags).w),
+      "bid128_round_integral_exact" => Dec128(bid128_round_integral_exact(x128(0), rnd, flags).w),
+      "bid128_round_integral_nearest_away" => Dec128(bid128_round_integral_nearest_away(x128(0), flags).w),
+      "bid128_round_integral_nearest_even" => Dec128(bid128_round_integral_nearest_even(x128(0), flags).w),
+      "bid128_round_integral_negative" => Dec128(bid128_round_integral_negative(x128(0), flags).w),
+      "bid128_round_integral_positive" => Dec128(bid128_round_integral_positive(x128(0), flags).w),
+      "bid128_round_integral_zero" => Dec128(bid128_round_integral_zero(x128(0), flags).w),
+      "bid128_scalbn" => Dec128(bid128_scalbn_round(x128(0), n(1) as i32, rnd, flags).w),
+      "bid128_sqrt" => Dec128(bid128_sqrt(x128(0), rnd, flags).w),
+      "bid128_sub" => Dec128(bid128_sub(x128(0), x128(1), rnd, flags).w),
+      "bid128_to_bid32" => Dec32(bid128_to_bid32(x128(0), rnd, flags).w),
+      "bid128_to_bid64" => Dec64(bid128_to_bid64(x128(0), rnd, flags).w),
+      "bid128_to_int32_int" => Int32(bid128_to_int32_int(x128(0), flags)),
+      "bid128_to_int64_int" => Int64(bid128_to_int64_int(x128(0), flags)),
+      "bid128_to_string" => Str(bid128_to_string(x128(0), flags)),
+      "bid128_to_uint32_int" => Uint32(bid128_to_uint32_int(x128(0), flags)),
+      "bid128_to_uint64_int" => Uint64(bid128_to_uint64_int(x128(0), flags)),
+      "bid64_add" => Dec64(bid64_add(x64(0), x64(1), rnd, flags).w),
+      "bid64_div" => Dec64(bid64_div(x64(0), x64(1), rnd, flags).w),
+      "bid64_from_int32" => Dec64(bid64_from_int32(n(0) as i32).w),
+      "bid64_from_int64" => Dec64(bid64_from_int64(n(0) as i64, rnd, flags).w),
+      "bid64_from_string" => Dec64(bid64_from_string(op(0), rnd, flags).w),
+      "bid64_mul" => Dec64(bid64_mul(x64(0), x64(1), rnd, flags).w),
+      "bid64_pow" => Dec64(bid64_pow(x64(0), x64(1), rnd, flags).w),
+      "bid64_quantize" => Dec64(bid64_quantize(x64(0), x64(1), rnd, flags).w),
+      "bid64_quiet_equal" => Int32(bid64_quiet_equal(x64(0), x64(1), flags) as i32),
+      "bid64_quiet_less" => Int32(bid64_quiet_less(x64(0), x64(1), flags) as i32),
+      "bid64_sqrt" => Dec64(bid64_sqrt(x64(0), rnd, flags).w),
+      "bid64_sub" => Dec64(bid64_sub(x64(0), x64(1), rnd, flags).w),
+      "bid64_to_bid128" => Dec128(bid64_to_bid128(x64(0), flags).w),
+      "bid64_to_bid32" => Dec32(bid64_to_bid32(x64(0), rnd, flags).w),
+      "bid64_to_int32_int" => Int32(bid64_to_int32_int(x64(0), flags)),
+      "bid64_to_int64_int" => Int64(bid64_to_int64_int(x64(0), flags)),
+      "bid64_to_string" => Str(bid64_to_string(x64(0), flags)),
+      "bid32_add" => Dec32(bid32_add(x32(0), x32(1), rnd, flags).w),
+      "bid32_div" => Dec32(bid32_div(x32(0), x32(1), rnd, flags).w),
+      "bid32_from_int32" => Dec32(bid32_from_int32(n(0) as i32, rnd, flags).w),
+      "bid32_from_int64" => Dec32(bid32_from_int64(n(0) as i64, rnd, flags).w),
+      "bid32_from_string" => Dec32(bid32_from_string(op(0), rnd, flags).w),
+      "bid32_mul" => Dec32(bid32_mul(x32(0), x32(1), rnd, flags).w),
+      "bid32_pow" => Dec32(bid32_pow(x32(0), x32(1), rnd, flags).w),
+      "bid32_quantize" => Dec32(bid32_quantize(x32(0), x32(1), rnd, flags).w),
+      "bid32_quiet_equal" => Int32(bid32_quiet_equal(x32(0), x32(1), flags) as i32),
+      "bid32_quiet_less" => Int32(bid32_quiet_less(x32(0), x32(1), flags) as i32),
+      "bid32_sqrt" => Dec32(bid32_sqrt(x32(0), rnd, flags).w),
+      "bid32_sub" => Dec32(bid32_sub(x32(0), x32(1), rnd, flags).w),
+      "bid32_to_bid128" => Dec128(bid32_to_bid128(x32(0), flags).w),
+      "bid32_to_bid64" => Dec64(bid32_to_bid64(x32(0), flags).w),
+      "bid32_to_int32_int" => Int32(bid32_to_int32_int(x32(0), flags)),
+      "bid32_to_int64_int" => Int64(bid32_to_int64_int(x32(0), flags)),
+      "bid32_to_string" => Str(bid32_to_string(x32(0), flags)),
+      "bid_dpd_to_bid128" => Dec128(bid_dpd_to_bid128(dpd128(x128(0))).w),
+      "bid_dpd_to_bid64" => Dec64(bid_dpd_to_bid64(x64(0).w).w),
+      "bid_dpd_to_bid32" => Dec32(bid_dpd_to_bid32(x32(0).w).w),
+      "bid_to_dpd128" => {
+        let x = bid_to_dpd128(x128(0));
+        Dec128(bid128_from_words((x >> 64) as u64, x as u64).w)
+      }
+      "bid_to_dpd64" => Dec64(bid_to_dpd64(x64(0))),
+      "bid_to_dpd32" => Dec32(bid_to_dpd32(x32(0))),
+      "bid_strtod128" => {
+        // null pointer can not be passed as a string slice, the library converts it like an empty string
+        let s = match op(0) {
+          "NULL" | "EMPTY" => String::new(),
+          s => format!("{}{}", v.prefix, s),
+        };
+        Dec128(bid_strtod128(&s).0.w)
+      }
+      _ => return None,
+    })
+  }
+
+  /// Parses the expected result as the same kind of value as the actual one.
+  fn expected(v: &Vector, actual: &Value) -> Value {
+    match actual {
+      Value::Dec128(_) => Value::Dec128(dec128(v.result, v.round).w),
+      Value::Dec64(_) => Value::Dec64(dec64(v.result, v.round).w),
+      Value::Dec32(_) => Value::Dec32(dec32(v.result, v.round).w),
+      Value::Frexp(_, _) => Value::Frexp(dec128(v.result, v.round).w, int(v.operands[1]) as i32),
+      Value::Int32(_) => Value::Int32(int(v.result) as i32),
+      Value::Uint32(_) => Value::Uint32(int(v.result) as u32),
+      Value::Int64(_) => Value::Int64(int(v.result) as i64),
+      Value::Uint64(_) => Value::Uint64(int(v.result) as u64),
+      Value::Str(_) => Value::Str(v.result.to_string()),
+    }
+  }
+
+  /// Converts string results back to decimals, like `readtest` does.
+  fn normalize(v: &Vector, value: Value) -> Value {
+    match value {
+      Value::Str(s) if v.function.starts_with("bid128") => Value::Dec128(dec128(&s, v.round).w),
+      Value::Str(s) if v.function.starts_with("bid64") => Value::Dec64(dec64(&s, v.round).w),
+      Value::Str(s) => Value::Dec32(dec32(&s, v.round).w),
+      other => other,
+    }
+  }
+
+  /// Converts the result of any format to 128-bit decimal, the coefficient and exponent are preserved.
+  fn widen(value: &Value) -> BID128 {
+    match value {
+      Value::Dec128(w) => BID128 { w: *w },
+      Value::Dec64(w) => bid64_to_bid128(BID64 { w: *w }, &mut FB_CLEAR.clone()),
+      Value::Dec32(w) => bid32_to_bid128(BID32 { w: *w }, &mut FB_CLEAR.clone()),
+      other => panic!("unexpected result {other:?}"),
+    }
+  }
+
+  /// Returns the difference between the expected and actual finite results in units of the last place
+  /// of the greater exponent, negative when the expected result is less, like `check128_rel` in `readtest.c`.
+  fn ulps(expected: BID128, actual: BID128, round: u32) -> Option<f64> {
+    let parts = |x: BID128| match bid128_to_parts(x) {
+      Parts::Finite(sign, coefficient, exponent) => Some((sign, coefficient, exponent)),
+      _ => None,
+    };
+    let (sign_a, _, exponent_a) = parts(expected)?;
+    let (sign_b, _, exponent_b) = parts(actual)?;
+    if sign_a != sign_b {
+      return None;
+    }
+    let mut flags = FB_CLEAR;
+    let (a, b) = if exponent_a < exponent_b {
+      (bid128_quantize(expected, actual, round, &mut flags), actual)
+    } else {
+      (expected, bid128_quantize(actual, expected, round, &mut flags))
+    };
+    let (_, coefficient_a, exponent_a) = parts(a)?;
+    let (_, coefficient_b, exponent_b) = parts(b)?;
+    if exponent_a != exponent_b {
+      return None;
+    }
+    let ulps = coefficient_a.abs_diff(coefficient_b) as f64;
+    Some(if bid128_quiet_less(expected, actual, &mut flags) { -ulps } else { ulps })
+  }
+
+  /// Compares transcendental results, infinite 128-bit results are replaced with the greatest finite number
+  /// of the same sign, other special values must be equal.
+  fn relative(v: &Vector, expected: &Value, actual: &Value) -> bool {
+    if expected == actual {
+      return true;
+    }
+    let special = |x: BID128| !bid128_is_finite(x);
+    let (mut a, mut b) = (widen(expected), widen(actual));
+    if special(a) || special(b) {
+      let saturate = |x: BID128| bid128_from_parts(Parts::Finite(bid128_is_signed(x), 9_999_999_999_999_999_999_999_999_999_999_999, 6111)).unwrap();
+      match expected {
+        Value::Dec128(_) if bid128_is_infinite(a) => a = saturate(a),
+        Value::Dec128(_) if bid128_is_infinite(b) => b = saturate(b),
+        _ => return false,
+      }
+    }
+    match ulps(a, b, v.round) {
+      Some(ulps) => (ulps + v.ulp).abs() <= tolerance(v.function)[v.round as usize],
+      None => false,
+    }
+  }
+
+  fn check(v: &Vector, expected: &Value, actual: &Value, flags: u32) -> bool {
+    match comparison(v.function) {
+      Comparison::Exact => actual == expected && flags == v.status,
+      Comparison::Equal => {
+        let (a, b) = (widen(expected), widen(actual));
+        (actual == expected || bid128_quiet_equal(a, b, &mut FB_CLEAR.clone())) && flags == v.status
+      }
+      Comparison::RelativeError => relative(v, expected, actual) && flags & TRANSCENDENTAL_FLAGS == v.status & TRANSCENDENTAL_FLAGS,
+    }
+  }
+
+  #[test]
+  fn test_readtest() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("IntelRDFPMathLib20U2/TESTS/readtest.in");
+    let content = fs::read_to_string(path).unwrap();
+    let (mut passed, mut skipped, mut failures) = (0, 0, vec![]);
+    let mut skipped_functions = HashSet::new();
+    for (index, text) in content.lines().enumerate() {
+      let v = match parse_vector(index + 1, text) {
+        Ok(Some(v)) => v,
+        Ok(None) => continue,
+        Err(reason) => {
+          failures.push(reason);
+          continue;
+        }
+      };
+      let listed = SKIP.iter().any(|(function, _)| *function == v.function);
+      let mut flags = FB_CLEAR;
+      let actual = match execute(&v, &mut flags) {
+        Some(_) if listed => {
+          failures.push(format!("line {}: bound function {} is listed in SKIP", v.line, v.function));
+          continue;
+        }
+        Some(actual) => actual,
+        None if listed => {
+          skipped += 1;
+          skipped_functions.insert(v.function);
+          continue;
+        }
+        None => {
+          failures.push(format!("line {}: function {} is neither bound nor listed in SKIP", v.line, v.function));
+          continue;
+        }
+      };
+      let expected = normalize(&v, expected(&v, &actual));
+      let actual = normalize(&v, actual);
+      if check(&v, &expected, &actual, flags) {
+        passed += 1;
+      } else {
+        failures.push(format!(
+          "line {}: {} {} {:?}: expected {:?} status {:02x}, actual {:?} status {:02x}",
+          v.line, v.function, v.round, v.operands, expected, v.status, actual, flags
+        ));
+      }
+    }
+    for (function, _) in SKIP.iter().filter(|(function, _)| !skipped_functions.contains(function)) {
+      failures.push(format!("function {function} listed in SKIP is not tested in readtest.in"));
+    }
+    assert!(passed > 0, "no readtest vectors passed");
+    assert_eq!(SKIPPED, skipped, "unexpected number of skipped readtest vectors");
+    assert!(failures.is_empty(), "{} readtest vectors failed:\n{}", failures.len(), failures.join("\n"));
+  }
+}
@@ -0,0 +1,89 @@
+/*
+ * MIT License
+ *
+ * Copyright (c) 2022 Dariusz Depta
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in all
+ * copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+ * AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+ * SOFTWARE.
+ */
+
+//! # Tests of JavaScript bindings
+//!
+//! Only successful operations are tested natively, errors can be created only in JavaScript.
+
+#[cfg(all(
+  feature = "bid128",
+  feature = "wasm-bindgen",
+  not(feature = "call-by-reference"),
+  not(feature = "global-rounding"),
+  not(feature = "global-exception-flags")
+))]
+mod tests_wasm {
+  use dfp_number_sys::*;
+
+  fn d(s: &str) -> Decimal {
+    Decimal::new(s).ok().unwrap()
+  }
+
+  #[test]
+  fn test_decimal_arithmetic() {
+    assert_eq!("0.3", d("0.1").add(&d("0.2")).ok().unwrap().to_js_string());
+    assert_eq!("-0.1", d("0.1").sub(&d("0.2")).ok().unwrap().to_js_string());
+    assert_eq!("0.90", d("0.3").mul(&d("3")).ok().unwrap().to_fixed(2).ok().unwrap());
+    assert_eq!("0.3333333333333333333333333333333333", d("1").div(&d("3")).ok().unwrap().to_js_string());
+    assert_eq!("1.414213562373095048801688724209698", d("2").sqrt().ok().unwrap().to_js_string());
+  }
+
+  #[test]
+  fn test_decimal_round() {
+    assert_eq!("2.35", d("2.345").round(2, RoundingModes::NearestAway).ok().unwrap().to_js_string());
+    assert_eq!("2.34", d("2.345").round(2, RoundingModes::NearestEven).ok().unwrap().to_js_string());
+    assert_eq!("-2.34", d("-2.345").round(2, RoundingModes::Upward).ok().unwrap().to_js_string());
+    assert_eq!("1200", d("1234").round(-2, RoundingModes::TowardZero).ok().unwrap().to_fixed(0).ok().unwrap());
+    assert_eq!("0", d("1234").round(-6111, RoundingModes::NearestEven).ok().unwrap().to_js_string());
+    assert_eq!(Some(0), d("1E-6176").round(6176, RoundingModes::NearestEven).ok().unwrap().compare(&d("1E-6176")));
+  }
+
+  #[test]
+  fn test_decimal_to_fixed() {
+    let fixed = d("1E-6176").to_fixed(6176).ok().unwrap();
+    assert_eq!(6178, fixed.len());
+    assert!(fixed.starts_with("0.000") && fixed.ends_with("001"));
+  }
+
+  #[test]
+  fn test_decimal_compare() {
+    assert_eq!(Some(-1), d("1.5").compare(&d("2")));
+    assert_eq!(Some(0), d("1.50").compare(&d("1.5")));
+    assert_eq!(Some(1), d("2").compare(&d("-2")));
+    assert_eq!(None, d("NaN").compare(&d("1")));
+  }
+
+  #[test]
+  fn test_decimal_properties() {
+    let x = Decimal::from_int(-25);
+    assert_eq!("-25", x.to_js_string());
+    assert_eq!("25", x.neg().to_js_string());
+    assert_eq!("25", x.abs().to_js_string());
+    assert!(x.is_negative());
+    assert!(x.is_finite());
+    assert!(!x.is_zero());
+    assert!(d("-0.00").is_zero());
+    assert!(!d("Inf").is_finite());
+  }
+}